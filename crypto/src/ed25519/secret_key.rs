@@ -1,6 +1,12 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    zeroize::Zeroize,
+};
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Eq, Ord, PartialOrd, Hash)]
+/// Expanded ed25519 secret key, the secret half followed by the public half.
+///
+/// The key is zeroed on drop and never printed in `Debug`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Eq, Ord, PartialOrd, Hash)]
 pub struct ED25519SecretKey(pub [u8; ed25519_dalek::KEYPAIR_LENGTH]);
 
 impl PartialEq for ED25519SecretKey {
@@ -9,12 +15,14 @@ impl PartialEq for ED25519SecretKey {
     }
 }
 
+impl Drop for ED25519SecretKey {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 impl core::fmt::Debug for ED25519SecretKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(
-            f,
-            "{}",
-            bs58::encode(&self.0[..ed25519_dalek::SECRET_KEY_LENGTH].to_vec()).into_string()
-        )
+        write!(f, "ED25519SecretKey(<redacted>)")
     }
 }
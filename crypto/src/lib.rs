@@ -11,7 +11,7 @@ pub use {
     errors::{ParseKeyError, ParseKeyTypeError, ParseSignatureError},
     key_type::KeyType,
     public_key::PublicKey,
-    secp256k1::{Secp256K1PublicKey, Secp256K1Signature},
+    secp256k1::{keccak256, Secp256K1PublicKey, Secp256K1Signature},
     secret_key::SecretKey,
     signature::Signature,
 };
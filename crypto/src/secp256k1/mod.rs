@@ -1,8 +1,20 @@
 mod public_key;
 mod signature;
 
+use sha2::Digest;
+
 pub const SECP256K1_SIGNATURE_LENGTH: usize = 65;
 pub const SECP256K1_PUBLIC_KEY_LENGTH: usize = 64;
 pub const SECP256K1_SECRET_KEY_SIZE: usize = 32;
+pub const ETH_ADDRESS_LENGTH: usize = 20;
 
 pub use {public_key::Secp256K1PublicKey, signature::Secp256K1Signature};
+
+/// The sha256 hash of the data, the `_prehashed` functions take a digest as is.
+pub(crate) fn secp256k1_message(data: &[u8]) -> libsecp256k1::Message {
+    libsecp256k1::Message::parse(&sha2::Sha256::digest(data).into())
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    sha3::Keccak256::digest(data).into()
+}
@@ -1,5 +1,5 @@
 use {
-    super::SECP256K1_PUBLIC_KEY_LENGTH,
+    super::{keccak256, ETH_ADDRESS_LENGTH, SECP256K1_PUBLIC_KEY_LENGTH},
     borsh::{BorshDeserialize, BorshSerialize},
     core::{cmp::Ordering, convert::TryFrom},
 };
@@ -7,6 +7,40 @@ use {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Hash)]
 pub struct Secp256K1PublicKey(pub [u8; SECP256K1_PUBLIC_KEY_LENGTH]);
 
+impl Secp256K1PublicKey {
+    /// Ethereum address, the last 20 bytes of the keccak256 hash of the uncompressed key.
+    pub fn eth_address(&self) -> [u8; ETH_ADDRESS_LENGTH] {
+        let hash = keccak256(&self.0);
+        let mut address = [0u8; ETH_ADDRESS_LENGTH];
+        address.copy_from_slice(&hash[32 - ETH_ADDRESS_LENGTH..]);
+
+        address
+    }
+
+    /// Ethereum address as a `0x` prefixed hex string with the EIP-55 mixed case checksum.
+    pub fn eth_checksum_address(&self) -> String {
+        let address = self
+            .eth_address()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        let hash = keccak256(address.as_bytes());
+        let checksum_address = address
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+                match nibble >= 8 {
+                    true => c.to_ascii_uppercase(),
+                    false => c,
+                }
+            })
+            .collect::<String>();
+
+        format!("0x{}", checksum_address)
+    }
+}
+
 impl From<&[u8]> for Secp256K1PublicKey {
     fn from(data: &[u8]) -> Self {
         Self(<[u8; SECP256K1_PUBLIC_KEY_LENGTH]>::try_from(<&[u8]>::clone(&data)).unwrap())
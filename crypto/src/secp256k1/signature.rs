@@ -1,5 +1,5 @@
 use {
-    super::{Secp256K1PublicKey, SECP256K1_SIGNATURE_LENGTH},
+    super::{secp256k1_message, Secp256K1PublicKey, SECP256K1_SIGNATURE_LENGTH},
     crate::ParseSignatureError,
     borsh::{BorshDeserialize, BorshSerialize},
     core::fmt::{Debug, Formatter},
@@ -44,26 +44,28 @@ impl Secp256K1Signature {
         r < SECP256K1_N && s < s_check
     }
 
+    /// The recovery id stored in the last byte of the signature.
+    pub fn recovery_id(&self) -> u8 {
+        self.0[SECP256K1_SIGNATURE_LENGTH - 1]
+    }
+
+    /// Recovers the public key which signed the sha256 hash of the data.
     pub fn recover(
         &self,
         data: &[u8],
         recovery_id: u8,
     ) -> Result<Secp256K1PublicKey, ParseSignatureError> {
-        let message = libsecp256k1::Message::parse_slice(data).map_err(|_| {
-            ParseSignatureError::InvalidData {
-                error_message: "failed to parse message".to_string(),
-            }
-        })?;
+        let message = secp256k1_message(data);
         let recovery_id = libsecp256k1::RecoveryId::parse(recovery_id).map_err(|_| {
             ParseSignatureError::InvalidData {
                 error_message: "failed to parse recovery_id".to_string(),
             }
         })?;
-        let signature = libsecp256k1::Signature::parse_standard_slice(&self.0).map_err(|_| {
-            ParseSignatureError::InvalidData {
+        let signature = libsecp256k1::Signature::parse_standard_slice(&self.0[..64]).map_err(
+            |_| ParseSignatureError::InvalidData {
                 error_message: "failed to parse signature".to_string(),
-            }
-        })?;
+            },
+        )?;
         let secp256k1_key =
             libsecp256k1::recover(&message, &signature, &recovery_id).map_err(|_| {
                 ParseSignatureError::InvalidData {
@@ -75,26 +77,48 @@ impl Secp256K1Signature {
         ))
     }
 
+    /// Verifies the signature of the sha256 hash of the data.
     pub fn verify(&self, data: &[u8], public_key: &[u8]) -> Result<(), ParseSignatureError> {
-        let public_key = libsecp256k1::PublicKey::parse_slice(&public_key, None).map_err(|_| {
+        self.verify_message(&secp256k1_message(data), public_key)
+    }
+
+    /// Verifies the signature of a 32 bytes digest, see `SecretKey::sign_prehashed`.
+    pub fn verify_prehashed(
+        &self,
+        digest: &[u8; 32],
+        public_key: &[u8],
+    ) -> Result<(), ParseSignatureError> {
+        self.verify_message(&libsecp256k1::Message::parse(digest), public_key)
+    }
+
+    fn verify_message(
+        &self,
+        message: &libsecp256k1::Message,
+        public_key: &[u8],
+    ) -> Result<(), ParseSignatureError> {
+        let public_key = libsecp256k1::PublicKey::parse_slice(public_key, None).map_err(|_| {
             ParseSignatureError::InvalidData {
                 error_message: "failed to parse public key".to_string(),
             }
         })?;
-        let message = libsecp256k1::Message::parse_slice(data).map_err(|_| {
-            ParseSignatureError::InvalidData {
-                error_message: "failed to parse message".to_string(),
-            }
-        })?;
 
-        let signature = libsecp256k1::Signature::parse_standard_slice(&self.0).map_err(|_| {
-            ParseSignatureError::InvalidData {
+        if !self.check_signature_values(true) {
+            return Err(ParseSignatureError::InvalidData {
+                error_message: "signature values out of range".to_string(),
+            });
+        }
+        let signature = libsecp256k1::Signature::parse_standard_slice(&self.0[..64]).map_err(
+            |_| ParseSignatureError::InvalidData {
                 error_message: "failed to parse signature".to_string(),
-            }
-        })?;
-
-        libsecp256k1::verify(&message, &signature, &public_key);
-        Ok(())
+            },
+        )?;
+
+        match libsecp256k1::verify(message, &signature, &public_key) {
+            true => Ok(()),
+            false => Err(ParseSignatureError::InvalidData {
+                error_message: "signature does not match".to_string(),
+            }),
+        }
     }
 }
 
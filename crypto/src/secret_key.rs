@@ -2,19 +2,27 @@ use {
     crate::{
         ed25519::{ED25519PublicKey, ED25519SecretKey},
         key_type::{split_key_type_data, KeyType},
-        secp256k1::{Secp256K1PublicKey, Secp256K1Signature, SECP256K1_SECRET_KEY_SIZE},
+        secp256k1::{
+            secp256k1_message,
+            Secp256K1PublicKey,
+            Secp256K1Signature,
+            SECP256K1_SECRET_KEY_SIZE,
+        },
         PublicKey,
         Signature,
     },
     core::{
-        fmt::{Debug, Display},
+        fmt::{Debug, Display, Formatter},
         str::FromStr,
     },
     ed25519_dalek::Signer,
+    zeroize::Zeroizing,
 };
 
 /// Secret key container supporting different curves.
-#[derive(Clone, Eq, PartialEq, Debug)]
+///
+/// Keys are zeroed on drop and redacted in `Debug` and `Display`, use `to_secret_string` to export them.
+#[derive(Clone, Eq, PartialEq)]
 pub enum SecretKey {
     ED25519(ED25519SecretKey),
     SECP256K1(libsecp256k1::SecretKey),
@@ -41,22 +49,38 @@ impl SecretKey {
         }
     }
 
+    /// ED25519 secret key expanded from a 32 bytes seed, e.g. a SLIP-10 derived key.
+    pub fn ed25519_from_seed(seed: &[u8; ed25519_dalek::SECRET_KEY_LENGTH]) -> SecretKey {
+        let secret_key =
+            ed25519_dalek::SecretKey::from_bytes(seed).expect("seed has the secret key length");
+        let public_key = ed25519_dalek::PublicKey::from(&secret_key);
+        let mut data = Zeroizing::new([0; ed25519_dalek::KEYPAIR_LENGTH]);
+        data[..ed25519_dalek::SECRET_KEY_LENGTH].copy_from_slice(secret_key.as_bytes());
+        data[ed25519_dalek::SECRET_KEY_LENGTH..].copy_from_slice(public_key.as_bytes());
+
+        SecretKey::ED25519(ED25519SecretKey(*data))
+    }
+
+    /// Signs the data, SECP256K1 signs its sha256 hash.
     pub fn sign(&self, data: &[u8]) -> Signature {
         match &self {
             SecretKey::ED25519(secret_key) => {
                 let keypair = ed25519_dalek::Keypair::from_bytes(&secret_key.0).unwrap();
                 Signature::ED25519(keypair.sign(data))
             }
+            SecretKey::SECP256K1(secret_key) => {
+                secp256k1_sign(&secp256k1_message(data), secret_key)
+            }
+        }
+    }
 
+    /// Signs a 32 bytes digest such as a transaction hash, SECP256K1 signs it without hashing it
+    /// again as nearcore expects.
+    pub fn sign_prehashed(&self, digest: &[u8; 32]) -> Signature {
+        match &self {
+            SecretKey::ED25519(_) => self.sign(digest),
             SecretKey::SECP256K1(secret_key) => {
-                let (_, recover_id) = libsecp256k1::sign(
-                    &libsecp256k1::Message::parse_slice(data).expect("32 bytes"),
-                    secret_key,
-                );
-                let mut buf = [0; 65];
-                buf[0..64].copy_from_slice(&data[0..64]);
-                buf[64] = recover_id.serialize();
-                Signature::SECP256K1(Secp256K1Signature(buf))
+                secp256k1_sign(&libsecp256k1::Message::parse(digest), secret_key)
             }
         }
     }
@@ -86,15 +110,40 @@ impl SecretKey {
     }
 }
 
-impl Display for SecretKey {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+impl SecretKey {
+    /// Exports the key as `<key type>:<base58 data>`, the format accepted by `from_str`.
+    pub fn to_secret_string(&self) -> Zeroizing<String> {
         let data = match self {
             SecretKey::ED25519(secret_key) => bs58::encode(&secret_key.0[..]).into_string(),
             SecretKey::SECP256K1(secret_key) => {
-                bs58::encode(&secret_key.serialize()[..]).into_string()
+                let bytes = Zeroizing::new(secret_key.serialize());
+                bs58::encode(&bytes[..]).into_string()
             }
         };
-        write!(f, "{}:{}", self.key_type(), data)
+        let data = Zeroizing::new(data);
+
+        Zeroizing::new(format!("{}:{}", self.key_type(), data.as_str()))
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        // ED25519 keys zero themselves, libsecp256k1 keys have to be cleared explicitly.
+        if let SecretKey::SECP256K1(secret_key) = self {
+            secret_key.clear();
+        }
+    }
+}
+
+impl Display for SecretKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}:<redacted>", self.key_type())
+    }
+}
+
+impl Debug for SecretKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "SecretKey({})", self)
     }
 }
 
@@ -105,7 +154,7 @@ impl FromStr for SecretKey {
         let (key_type, key_data) = split_key_type_data(s)?;
         match key_type {
             KeyType::ED25519 => {
-                let mut array = [0; ed25519_dalek::KEYPAIR_LENGTH];
+                let mut array = Zeroizing::new([0; ed25519_dalek::KEYPAIR_LENGTH]);
                 let length = bs58::decode(key_data).into(&mut array[..]).map_err(|err| {
                     Self::Err::InvalidData {
                         error_message: err.to_string(),
@@ -117,10 +166,10 @@ impl FromStr for SecretKey {
                         received_length: length,
                     });
                 }
-                Ok(Self::ED25519(ED25519SecretKey(array)))
+                Ok(Self::ED25519(ED25519SecretKey(*array)))
             }
             KeyType::SECP256K1 => {
-                let mut array = [0; SECP256K1_SECRET_KEY_SIZE];
+                let mut array = Zeroizing::new([0; SECP256K1_SECRET_KEY_SIZE]);
                 let length = bs58::decode(key_data).into(&mut array[..]).map_err(|err| {
                     Self::Err::InvalidData {
                         error_message: err.to_string(),
@@ -133,7 +182,7 @@ impl FromStr for SecretKey {
                     });
                 }
                 Ok(Self::SECP256K1(
-                    libsecp256k1::SecretKey::parse_slice(&array).map_err(|err| {
+                    libsecp256k1::SecretKey::parse_slice(&array[..]).map_err(|err| {
                         Self::Err::InvalidData {
                             error_message: err.to_string(),
                         }
@@ -144,30 +193,137 @@ impl FromStr for SecretKey {
     }
 }
 
-impl serde::Serialize for SecretKey {
-    fn serialize<S>(
-        &self,
-        serializer: S,
-    ) -> Result<<S as serde::Serializer>::Ok, <S as serde::Serializer>::Error>
-    where
-        S: serde::Serializer,
-    {
-        let data = match self {
-            SecretKey::ED25519(secret_key) => bs58::encode(&secret_key.0[..]).into_string(),
-            SecretKey::SECP256K1(secret_key) => {
-                bs58::encode(&secret_key.serialize()[..]).into_string()
-            }
-        };
-        serializer.serialize_str(&format!("{}:{}", self.key_type(), data))
-    }
-}
-
 impl<'de> serde::Deserialize<'de> for SecretKey {
     fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        let s = Zeroizing::new(<String as serde::Deserialize>::deserialize(deserializer)?);
         Self::from_str(&s).map_err(|err| serde::de::Error::custom(err.to_string()))
     }
 }
+
+fn secp256k1_sign(
+    message: &libsecp256k1::Message,
+    secret_key: &libsecp256k1::SecretKey,
+) -> Signature {
+    let (mut signature, recovery_id) = libsecp256k1::sign(message, secret_key);
+    let mut recovery_id = recovery_id.serialize();
+    // Only the lower half of s is accepted to avoid malleable signatures.
+    if signature.s.is_high() {
+        signature.normalize_s();
+        recovery_id ^= 1;
+    }
+    let mut buf = [0; 65];
+    buf[0..64].copy_from_slice(&signature.serialize());
+    buf[64] = recovery_id;
+
+    Signature::SECP256K1(Secp256K1Signature(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(data: &str) -> Vec<u8> {
+        (0..data.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&data[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn secp256k1_secret_key(data: &str) -> SecretKey {
+        SecretKey::SECP256K1(libsecp256k1::SecretKey::parse_slice(&from_hex(data)).unwrap())
+    }
+
+    #[test]
+    pub fn test_secp256k1_rfc6979_vector() {
+        use sha2::Digest;
+        let secret_key = secp256k1_secret_key(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        );
+        let digest = sha2::Sha256::digest(b"Satoshi Nakamoto");
+        let signature = match secret_key.sign_prehashed(&digest.into()) {
+            Signature::SECP256K1(signature) => signature,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(
+            signature.0[..64].to_vec(),
+            from_hex(concat!(
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+                "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+            ))
+        );
+        assert!(signature.check_signature_values(true));
+        // Signing arbitrary length data signs its sha256 hash.
+        assert_eq!(
+            secret_key.sign(b"Satoshi Nakamoto"),
+            Signature::SECP256K1(signature)
+        );
+    }
+
+    #[test]
+    pub fn test_secp256k1_sign_verify_and_recover() {
+        let secret_key = SecretKey::from_random(KeyType::SECP256K1);
+        let public_key = secret_key.public_key();
+
+        for data in [&b"hello world"[..], &[7u8; 32][..]] {
+            let signature = secret_key.sign(data);
+            assert!(signature.verify(data, &public_key));
+            assert!(!signature.verify(b"other data", &public_key));
+
+            let signature = match signature {
+                Signature::SECP256K1(signature) => signature,
+                _ => unreachable!(),
+            };
+            let recovered = signature.recover(data, signature.recovery_id()).unwrap();
+            assert_eq!(PublicKey::SECP256K1(recovered), public_key);
+        }
+
+        let digest = [7u8; 32];
+        let signature = secret_key.sign_prehashed(&digest);
+        assert!(signature.verify_prehashed(&digest, &public_key));
+        assert!(!signature.verify(&digest, &public_key));
+        assert_ne!(signature, secret_key.sign(&digest));
+    }
+
+    #[test]
+    pub fn test_eth_address() {
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf",
+            ),
+            (
+                "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+                "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+            ),
+        ];
+
+        for (secret_key, address) in vectors {
+            let public_key = match secp256k1_secret_key(secret_key).public_key() {
+                PublicKey::SECP256K1(public_key) => public_key,
+                _ => unreachable!(),
+            };
+            assert_eq!(public_key.eth_checksum_address(), address);
+            assert_eq!(
+                public_key.eth_address().to_vec(),
+                from_hex(&address[2..].to_ascii_lowercase())
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_secret_key_is_redacted() {
+        for key_type in [KeyType::ED25519, KeyType::SECP256K1] {
+            let secret_key = SecretKey::from_random(key_type);
+            let secret_string = secret_key.to_secret_string();
+            let data = secret_string.split(':').nth(1).unwrap();
+
+            assert!(!format!("{:?}", secret_key).contains(data));
+            assert!(!secret_key.to_string().contains(data));
+            assert_eq!(SecretKey::from_str(&secret_string).unwrap(), secret_key);
+        }
+    }
+}
@@ -71,6 +71,16 @@ impl Signature {
         }
     }
 
+    /// Verifies the signature of a 32 bytes digest, see `SecretKey::sign_prehashed`.
+    pub fn verify_prehashed(&self, digest: &[u8; 32], public_key: &PublicKey) -> bool {
+        match (&self, public_key) {
+            (Signature::SECP256K1(signature), PublicKey::SECP256K1(public_key)) => {
+                signature.verify_prehashed(digest, &public_key.0).is_ok()
+            }
+            _ => self.verify(digest, public_key),
+        }
+    }
+
     pub fn key_type(&self) -> KeyType {
         match self {
             Signature::ED25519(_) => KeyType::ED25519,
@@ -0,0 +1,126 @@
+//! Parses the gas and deposit strings of the attributes, the way `NearGas` and `NearToken`
+//! parse them, without depending on the primitives crate.
+
+const TGAS_DECIMALS: usize = 12;
+const GGAS_DECIMALS: usize = 9;
+const NEAR_DECIMALS: usize = 24;
+
+/// Parses `50 TGas`, `5 GGas` or `100 gas` into gas.
+pub fn parse_gas(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let (amount, unit) = split_unit(value);
+    let decimals = match unit.as_str() {
+        "" => {
+            return Err(format!(
+                "missing unit in '{}', expected TGas, GGas or gas",
+                value
+            ))
+        }
+        "tgas" => TGAS_DECIMALS,
+        "ggas" => GGAS_DECIMALS,
+        "gas" => 0,
+        _ => {
+            return Err(format!(
+                "unknown unit '{}', expected TGas, GGas or gas",
+                unit
+            ))
+        }
+    };
+
+    parse_decimal(value, &amount, decimals)
+        .and_then(|gas| u64::try_from(gas).map_err(|_| format!("'{}' is too large", value)))
+}
+
+/// Parses `1.5 NEAR` or `1 yocto` into yoctoNEAR.
+pub fn parse_deposit(value: &str) -> Result<u128, String> {
+    let value = value.trim();
+    let (amount, unit) = split_unit(value);
+    let decimals = match unit.as_str() {
+        "" => {
+            return Err(format!(
+                "missing unit in '{}', expected NEAR or yocto",
+                value
+            ))
+        }
+        "near" | "n" => NEAR_DECIMALS,
+        "yocto" | "yoctonear" => 0,
+        _ => return Err(format!("unknown unit '{}', expected NEAR or yocto", unit)),
+    };
+
+    parse_decimal(value, &amount, decimals)
+}
+
+/// Splits `1.5 NEAR` into the amount without `_` separators and the lowercase unit.
+fn split_unit(value: &str) -> (String, String) {
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '_'))
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+
+    (amount.replace('_', ""), unit.trim().to_ascii_lowercase())
+}
+
+/// Parses `amount` of `value` as a decimal number scaled by 10^`decimals`.
+fn parse_decimal(value: &str, amount: &str, decimals: usize) -> Result<u128, String> {
+    let overflow = || format!("'{}' is too large", value);
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+    if (whole.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+        return Err(format!("invalid amount '{}'", value));
+    }
+    if fraction.len() > decimals {
+        return Err(format!("'{}' has too many decimals", value));
+    }
+
+    let scale = |digits: usize| 10u128.checked_pow(digits as u32).ok_or_else(overflow);
+    let whole = match whole.is_empty() {
+        true => 0,
+        false => whole.parse::<u128>().map_err(|_| overflow())?,
+    };
+    let fraction = match fraction.trim_end_matches('0') {
+        "" => 0,
+        trimmed => trimmed
+            .parse::<u128>()
+            .map_err(|_| overflow())?
+            .checked_mul(scale(decimals - trimmed.len())?)
+            .ok_or_else(overflow)?,
+    };
+
+    match whole {
+        0 => Ok(fraction),
+        whole => whole
+            .checked_mul(scale(decimals)?)
+            .and_then(|whole| whole.checked_add(fraction))
+            .ok_or_else(overflow),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_amounts() {
+        assert_eq!(parse_gas("50 TGas"), Ok(50_000_000_000_000));
+        assert_eq!(parse_gas("1.5 GGas"), Ok(1_500_000_000));
+        assert_eq!(parse_gas("100 gas"), Ok(100));
+        assert_eq!(parse_deposit("1 yocto"), Ok(1));
+        assert_eq!(
+            parse_deposit("0.5 NEAR"),
+            Ok(500_000_000_000_000_000_000_000)
+        );
+        assert_eq!(parse_deposit("1_000 yocto"), Ok(1_000));
+
+        assert!(parse_gas("30").unwrap_err().contains("missing unit"));
+        assert!(parse_gas("30 TG").unwrap_err().contains("unknown unit"));
+        assert!(parse_gas("0.5 gas")
+            .unwrap_err()
+            .contains("too many decimals"));
+        assert!(parse_gas("1000000000 TGas")
+            .unwrap_err()
+            .contains("too large"));
+        assert!(parse_deposit(". NEAR")
+            .unwrap_err()
+            .contains("invalid amount"));
+    }
+}
@@ -0,0 +1,337 @@
+use {
+    crate::amount::{parse_deposit, parse_gas},
+    proc_macro2::{Span, TokenStream},
+    quote::{format_ident, quote},
+    syn::{
+        parse::Parser,
+        punctuated::Punctuated,
+        spanned::Spanned,
+        Attribute,
+        Error,
+        FnArg,
+        ItemTrait,
+        Lit,
+        Meta,
+        NestedMeta,
+        Pat,
+        ReturnType,
+        Signature,
+        Token,
+        TraitItem,
+    },
+};
+
+/// 30 TGas.
+const DEFAULT_GAS: u64 = 30_000_000_000_000;
+
+/// Gas and yoctoNEAR attached to a change method.
+#[derive(Clone, Copy)]
+struct CallOptions {
+    gas: u64,
+    deposit: u128,
+}
+
+impl CallOptions {
+    /// Reads `gas` and `deposit`, as strings with units or as integers of gas and yoctoNEAR.
+    fn parse(args: &Punctuated<NestedMeta, Token![,]>, defaults: Self) -> syn::Result<Self> {
+        let mut options = defaults;
+
+        for arg in args {
+            let name_value = match arg {
+                NestedMeta::Meta(Meta::NameValue(name_value)) => name_value,
+                _ => {
+                    return Err(Error::new(
+                        arg.span(),
+                        "expected `gas = ..` or `deposit = ..`",
+                    ))
+                }
+            };
+            let invalid = |error: String| Error::new(name_value.lit.span(), error);
+
+            match (name_value.path.get_ident(), &name_value.lit) {
+                (Some(ident), Lit::Str(value)) if ident == "gas" => {
+                    options.gas = parse_gas(&value.value()).map_err(invalid)?;
+                }
+                (Some(ident), Lit::Int(value)) if ident == "gas" => {
+                    options.gas = value.base10_parse()?;
+                }
+                (Some(ident), Lit::Str(value)) if ident == "deposit" => {
+                    options.deposit = parse_deposit(&value.value()).map_err(invalid)?;
+                }
+                (Some(ident), Lit::Int(value)) if ident == "deposit" => {
+                    options.deposit = value.base10_parse()?;
+                }
+                _ => {
+                    return Err(Error::new(
+                        name_value.span(),
+                        "expected `gas = ..` or `deposit = ..`",
+                    ))
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+enum MethodKind {
+    View,
+    Call(CallOptions),
+}
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let args = Punctuated::<NestedMeta, Token![,]>::parse_terminated.parse2(attr)?;
+    let defaults = CallOptions::parse(
+        &args,
+        CallOptions {
+            gas: DEFAULT_GAS,
+            deposit: 0,
+        },
+    )?;
+    let mut item_trait: ItemTrait = syn::parse2(item)?;
+    let vis = item_trait.vis.clone();
+    let client = format_ident!("{}Client", item_trait.ident);
+    let doc = format!(
+        "Client of the contract described by [`{}`].",
+        item_trait.ident
+    );
+    let mut methods = vec![];
+
+    for item in item_trait.items.iter_mut() {
+        let method = match item {
+            TraitItem::Method(method) => method,
+            item => return Err(Error::new(item.span(), "only methods are supported")),
+        };
+        let kind = take_method_kind(&mut method.attrs, defaults, method.sig.span())?;
+
+        methods.push(expand_method(&method.sig, &method.attrs, kind)?);
+    }
+
+    Ok(quote! {
+        #item_trait
+
+        #[doc = #doc]
+        #[derive(Clone, Debug, PartialEq)]
+        #vis struct #client {
+            contract: ::near_anywhere::contract::Contract,
+        }
+
+        impl #client {
+            pub fn new(account: ::near_anywhere::account::Account, contract_id: &str) -> Self {
+                Self {
+                    contract: ::near_anywhere::contract::Contract::new(account, contract_id),
+                }
+            }
+
+            pub fn contract(&self) -> &::near_anywhere::contract::Contract {
+                &self.contract
+            }
+
+            #(#methods)*
+        }
+    })
+}
+
+/// Removes the `#[view]` or `#[call]` attribute of a method and returns its kind.
+fn take_method_kind(
+    attrs: &mut Vec<Attribute>,
+    defaults: CallOptions,
+    span: Span,
+) -> syn::Result<MethodKind> {
+    let mut kind = None;
+    let mut other_attrs = vec![];
+
+    for attr in attrs.drain(..) {
+        let method_kind = if attr.path.is_ident("view") {
+            match attr.parse_meta()? {
+                Meta::Path(_) => MethodKind::View,
+                _ => return Err(Error::new(attr.span(), "`view` takes no arguments")),
+            }
+        } else if attr.path.is_ident("call") {
+            match attr.parse_meta()? {
+                Meta::Path(_) => MethodKind::Call(defaults),
+                Meta::List(list) => MethodKind::Call(CallOptions::parse(&list.nested, defaults)?),
+                Meta::NameValue(_) => {
+                    return Err(Error::new(attr.span(), "expected `call` or `call(..)`"))
+                }
+            }
+        } else {
+            other_attrs.push(attr);
+            continue;
+        };
+
+        if kind.replace(method_kind).is_some() {
+            return Err(Error::new(
+                attr.span(),
+                "a method is either `#[view]` or `#[call]`",
+            ));
+        }
+    }
+
+    *attrs = other_attrs;
+    kind.ok_or_else(|| Error::new(span, "mark the method with `#[view]` or `#[call]`"))
+}
+
+fn expand_method(
+    sig: &Signature,
+    attrs: &[Attribute],
+    kind: MethodKind,
+) -> syn::Result<TokenStream> {
+    let name = &sig.ident;
+    let method_name = name.to_string();
+    let mut idents = vec![];
+    let mut types = vec![];
+
+    match sig.receiver() {
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() => {}
+        _ => {
+            return Err(Error::new(
+                sig.span(),
+                "methods must take `&self` or `&mut self`",
+            ))
+        }
+    }
+
+    for input in sig.inputs.iter() {
+        if let FnArg::Typed(pat_type) = input {
+            match pat_type.pat.as_ref() {
+                Pat::Ident(pat) => idents.push(pat.ident.clone()),
+                pat => return Err(Error::new(pat.span(), "arguments must be identifiers")),
+            }
+            types.push(pat_type.ty.clone());
+        }
+    }
+
+    let keys = idents.iter().map(|ident| ident.to_string());
+    let args = quote! {
+        ::near_anywhere::contract::serde_json::json!({ #(#keys: #idents),* })
+    };
+    let output = match &sig.output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => quote!(#ty),
+    };
+    let result = quote! {
+        ::core::result::Result<
+            ::near_anywhere::contract::ContractResult<#output>,
+            ::near_anywhere::contract::ContractError,
+        >
+    };
+
+    Ok(match kind {
+        MethodKind::View => quote! {
+            #(#attrs)*
+            pub async fn #name(&self, #(#idents: #types),*) -> #result {
+                self.contract.view(#method_name, &#args).await
+            }
+        },
+        MethodKind::Call(options) => {
+            let gas = options.gas;
+            let deposit = options.deposit;
+
+            quote! {
+                #(#attrs)*
+                pub async fn #name(&mut self, #(#idents: #types),*) -> #result {
+                    self.contract
+                        .call(
+                            #method_name,
+                            &#args,
+                            ::near_anywhere::primitives::types::NearGas::from_gas(#gas),
+                            ::near_anywhere::primitives::types::NearToken::from_yoctonear(#deposit),
+                        )
+                        .await
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_expand() {
+        let tokens = expand(
+            quote!(gas = "50 TGas"),
+            quote! {
+                pub trait Counter {
+                    #[view]
+                    fn get_num(&self) -> i8;
+                    #[call(deposit = "1 yocto")]
+                    fn increment(&mut self, by: u8);
+                }
+            },
+        )
+        .unwrap()
+        .to_string();
+
+        assert!(tokens.contains("pub struct CounterClient"));
+        assert!(tokens.contains("50000000000000u64"));
+        assert!(tokens.contains("1u128"));
+        assert!(!tokens.contains("# [view]"));
+
+        let error = expand(
+            quote!(),
+            quote! {
+                trait Counter {
+                    fn get_num(&self) -> i8;
+                }
+            },
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("#[view]"));
+
+        let error = expand(
+            quote!(),
+            quote! {
+                trait Counter {
+                    #[call(gas = "30 TG")]
+                    fn increment(&mut self);
+                }
+            },
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("unknown unit"));
+    }
+
+    #[test]
+    pub fn test_expand_errors() {
+        let error = expand(
+            quote!(gas = "1.2.3 TGas"),
+            quote! {
+                trait Counter {
+                    #[view]
+                    fn get_num(&self) -> i8;
+                }
+            },
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("invalid amount"));
+
+        let error = expand(
+            quote!(),
+            quote! {
+                trait Counter {
+                    #[view]
+                    fn get_num() -> i8;
+                }
+            },
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("`&self` or `&mut self`"));
+
+        let error = expand(
+            quote!(),
+            quote! {
+                trait Counter {
+                    #[call(gas = "50 TGas", memo = "hi")]
+                    fn increment(&mut self);
+                }
+            },
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("expected `gas = ..` or `deposit = ..`"));
+    }
+}
@@ -0,0 +1,17 @@
+mod amount;
+mod contract;
+
+use proc_macro::TokenStream;
+
+/// Generates `<Trait>Client`, an async client of the contract described by the trait.
+///
+/// Methods marked `#[view]` call `Account::view_function`, methods marked `#[call]` call
+/// `Account::function_call` with the `gas` and `deposit` of the attribute, e.g.
+/// `#[call(gas = "50 TGas", deposit = "1 yocto")]`. Defaults for every change method can be
+/// set with `#[contract(gas = ..., deposit = ...)]`, otherwise 30 TGas and no deposit are used.
+#[proc_macro_attribute]
+pub fn contract(attr: TokenStream, item: TokenStream) -> TokenStream {
+    contract::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -1,8 +1,19 @@
 use {
-    super::{full_access_key, funcation_call_access_key, AccountAuthorizedApp, AccountBalance},
+    super::{
+        full_access_key,
+        funcation_call_access_key,
+        AccountAccessKey,
+        AccountAccessKeyAudit,
+        AccountAuthorizedApp,
+        AccountBalance,
+        AccountError,
+        EpochInfo,
+        StorageCalculator,
+    },
     crate::{
         client::RpcError,
-        crypto::PublicKey,
+        crypto::{KeyType, PublicKey},
+        hash::CryptoHash,
         near::Connection,
         primitives::{
             access_key::{
@@ -24,16 +35,33 @@ use {
                 TransferAction,
             },
             near::NearRpcUser,
-            query::FunctionArgs,
+            query::{FunctionArgs, ViewCallError},
             transaction::{
                 CallResult,
                 FinalExecutionOutcomeView,
+                FinalExecutionStatus,
                 SignedTransaction,
                 ViewStateResult,
             },
-            types::{AccountId, Balance, BlockReference, Finality, Gas, StoreKey},
+            types::{
+                AccountId,
+                Balance,
+                BlockId,
+                BlockReference,
+                Finality,
+                NearGas,
+                NearToken,
+                StorageUsage,
+                StoreKey,
+            },
+        },
+        signer::SignerError,
+        transaction::{
+            create_transaction,
+            estimate_transaction_fees,
+            sign_transaction,
+            TransactionFeeEstimate,
         },
-        transaction::sign_transaction_with_receiver,
     },
     hashbrown::HashMap,
 };
@@ -67,12 +95,23 @@ impl Account {
         receiver_id: &str,
         actions: Vec<Action>,
     ) -> Result<SignedTransaction, RpcError> {
-        let signer = self.connection.signer.clone();
-        let network_id = self.connection.network_id.clone();
-        let account_id = self.account_id.clone();
         let access_key_info = self
             .find_function_access_key(receiver_id, actions.clone())
             .await?;
+
+        self.sign_transaction_with_access_key(receiver_id, actions, access_key_info)
+            .await
+    }
+
+    async fn sign_transaction_with_access_key(
+        &self,
+        receiver_id: &str,
+        actions: Vec<Action>,
+        access_key_info: AccessKeyInfoView,
+    ) -> Result<SignedTransaction, RpcError> {
+        let signer = self.connection.signer.clone();
+        let network_id = self.connection.network_id.clone();
+        let account_id = self.account_id.clone();
         let access_key = access_key_info.access_key;
         let block = self
             .near_rpc_user
@@ -80,71 +119,299 @@ impl Account {
             .await?;
         let block_hash = block.header.hash;
         let nonce = access_key.nonce + 1;
-        let signed_transaction = sign_transaction_with_receiver(
+        let transaction = create_transaction(
+            &account_id,
+            access_key_info.public_key,
             receiver_id,
             nonce,
             actions,
             block_hash,
-            signer,
-            &account_id,
-            &network_id,
-        )
-        .await;
+        );
+        let signed_transaction =
+            sign_transaction(transaction, signer.as_ref(), &account_id, &network_id).await?;
 
         Ok(signed_transaction)
     }
 
-    pub async fn sign_and_send_transaction(
-        &mut self,
+    /// Sends a transaction signed with the given key, the outcome is returned even if it failed.
+    async fn send_transaction_outcome(
+        &self,
         receiver_id: &str,
         actions: Vec<Action>,
+        access_key_info: AccessKeyInfoView,
     ) -> Result<FinalExecutionOutcomeView, RpcError> {
-        let signed_transaction = self.sign_transaction(&receiver_id, actions).await.unwrap();
+        let signed_transaction = self
+            .sign_transaction_with_access_key(receiver_id, actions, access_key_info)
+            .await?;
 
         self.near_rpc_user
             .send_transaction(signed_transaction)
             .await
     }
 
+    /// Sends a transaction signed with the given key, failed transactions are errors.
+    async fn send_transaction_with_access_key(
+        &self,
+        receiver_id: &str,
+        actions: Vec<Action>,
+        access_key_info: AccessKeyInfoView,
+    ) -> Result<FinalExecutionOutcomeView, RpcError> {
+        let outcome = self
+            .send_transaction_outcome(receiver_id, actions, access_key_info)
+            .await?;
+
+        check_outcome(outcome)
+    }
+
+    pub async fn sign_and_send_transaction(
+        &mut self,
+        receiver_id: &str,
+        actions: Vec<Action>,
+    ) -> Result<FinalExecutionOutcomeView, RpcError> {
+        let access_key_info = self
+            .find_function_access_key(receiver_id, actions.clone())
+            .await?;
+
+        self.send_transaction_with_access_key(receiver_id, actions, access_key_info)
+            .await
+    }
+
+    /// Finds a local full access key of the account, the default key first.
     pub async fn find_access_key(&mut self) -> Result<AccessKeyInfoView, RpcError> {
-        let account_id = self.account_id.clone();
-        let public_key = self
+        let access_keys = self.get_local_access_keys().await?;
+
+        access_keys
+            .into_iter()
+            .find(|item| item.access_key.permission == AccessKeyPermissionView::FullAccess)
+            .ok_or_else(|| {
+                AccountError::FullAccessKeyNotFound {
+                    account_id: self.account_id.to_string(),
+                }
+                .into()
+            })
+    }
+
+    /// Finds a local key able to sign `actions` for `receiver_id`. A function call key is
+    /// chosen when the actions are a single function call without deposit allowed by the key
+    /// and its remaining allowance covers the gas, otherwise a full access key is needed.
+    pub async fn find_function_access_key(
+        &mut self,
+        receiver_id: &str,
+        actions: Vec<Action>,
+    ) -> Result<AccessKeyInfoView, RpcError> {
+        let access_keys = self.get_local_access_keys().await?;
+        let mut error = AccountError::FullAccessKeyNotFound {
+            account_id: self.account_id.to_string(),
+        };
+
+        if let [Action::FunctionCall(action)] = actions.as_slice() {
+            if action.deposit == 0 {
+                let block = self
+                    .near_rpc_user
+                    .get_block(BlockReference::Finality(Finality::Final))
+                    .await?;
+                let required = action.gas as u128 * block.header.gas_price;
+
+                error = AccountError::FunctionCallKeyNotFound {
+                    account_id: self.account_id.to_string(),
+                    receiver_id: receiver_id.to_string(),
+                    method_name: action.method_name.clone(),
+                };
+
+                for item in access_keys.iter() {
+                    if let AccessKeyPermissionView::FunctionCall { allowance, .. } =
+                        &item.access_key.permission
+                    {
+                        if !permission_allows(
+                            &item.access_key.permission,
+                            receiver_id,
+                            &action.method_name,
+                        ) {
+                            continue;
+                        }
+
+                        match allowance {
+                            Some(allowance) if *allowance < required => {
+                                error = AccountError::InsufficientAllowance {
+                                    public_key: item.public_key.to_string(),
+                                    allowance: *allowance,
+                                    required,
+                                };
+                            }
+                            _ => return Ok(item.clone()),
+                        }
+                    }
+                }
+            }
+        }
+
+        access_keys
+            .into_iter()
+            .find(|item| item.access_key.permission == AccessKeyPermissionView::FullAccess)
+            .ok_or_else(|| error.into())
+    }
+
+    /// On-chain access keys of the local keys of the account, the default key first. Also
+    /// refreshes the access key cache.
+    async fn get_local_access_keys(&mut self) -> Result<Vec<AccessKeyInfoView>, RpcError> {
+        let public_keys = self
             .connection
             .signer
-            .get_public_key(&account_id, &self.connection.network_id)
-            .expect("missing public key");
-        let key = public_key.to_string();
-        let cached_access_key = self.access_key_by_public_key_cache.get(&key);
+            .get_public_keys(&self.account_id, &self.connection.network_id)
+            .await?;
 
-        match cached_access_key {
-            Some(access_key) => Ok(AccessKeyInfoView {
-                public_key,
-                access_key: access_key.clone().into(),
-            }),
-            None => {
-                let access_key_view = self
-                    .near_rpc_user
-                    .view_access_key(&account_id, &public_key)
-                    .await
-                    .unwrap();
+        for item in self.get_access_keys().await?.keys {
+            self.access_key_by_public_key_cache
+                .insert(item.public_key.to_string(), item.access_key);
+        }
 
+        Ok(public_keys
+            .into_iter()
+            .filter_map(|public_key| {
                 self.access_key_by_public_key_cache
-                    .insert(public_key.to_string(), access_key_view.clone());
+                    .get(&public_key.to_string())
+                    .map(|access_key| AccessKeyInfoView {
+                        public_key,
+                        access_key: access_key.clone(),
+                    })
+            })
+            .collect())
+    }
 
-                Ok(AccessKeyInfoView {
-                    public_key,
-                    access_key: access_key_view,
-                })
-            }
+    /// Estimates the fees of sending `actions` to `receiver_id` at the current gas price, so the
+    /// cost can be shown before signing.
+    pub async fn estimate_transaction_fees(
+        &self,
+        receiver_id: &str,
+        actions: Vec<Action>,
+    ) -> Result<TransactionFeeEstimate, RpcError> {
+        let block = self
+            .near_rpc_user
+            .get_block(BlockReference::Finality(Finality::Final))
+            .await?;
+        let protocol_config = self
+            .near_rpc_user
+            .get_protocol_config(BlockReference::Finality(Finality::Final))
+            .await?;
+        let gas_price = self
+            .near_rpc_user
+            .get_gas_price(block.header.height)
+            .await?;
+        // Signing key, nonce and block hash do not change fees, no local key is needed.
+        let transaction = create_transaction(
+            &self.account_id,
+            PublicKey::empty(KeyType::ED25519),
+            receiver_id,
+            0,
+            actions,
+            block.header.hash,
+        );
+
+        Ok(estimate_transaction_fees(
+            &transaction,
+            &protocol_config.runtime_config.transaction_costs,
+            &gas_price,
+        ))
+    }
+
+    /// Replaces a full access key of the account, the default key first, with a new key and
+    /// returns its public key.
+    ///
+    /// The new key is added on-chain and checked with a transaction without actions before it
+    /// becomes the default local key and the old key is deleted. Completed steps are undone when
+    /// a later step fails.
+    pub async fn rotate_key(&mut self) -> Result<PublicKey, RpcError> {
+        let signer = self.connection.signer.clone();
+        let network_id = self.connection.network_id.clone();
+        let account_id = self.account_id.clone();
+        let old_key = self.find_access_key().await?;
+        let public_key = signer.create_key(&account_id, &network_id).await?;
+
+        let actions = vec![Action::AddKey(AddKeyAction {
+            public_key: public_key.clone(),
+            access_key: full_access_key(),
+        })];
+        if let Err(error) = self
+            .send_transaction_with_access_key(&account_id, actions, old_key.clone())
+            .await
+        {
+            signer
+                .remove_key(&account_id, &network_id, &public_key)
+                .await
+                .ok();
+            return Err(error);
+        }
+
+        if let Err(error) = self.switch_key(&old_key.public_key, &public_key).await {
+            self.rollback_key_rotation(&old_key.public_key, &public_key)
+                .await;
+            return Err(error);
         }
+
+        // The old key is already deleted on-chain, a local copy left behind is harmless.
+        signer
+            .remove_key(&account_id, &network_id, &old_key.public_key)
+            .await
+            .ok();
+
+        Ok(public_key)
     }
 
-    pub async fn find_function_access_key(
+    /// Checks the new key on-chain, makes it the default local key and deletes the old key.
+    async fn switch_key(
         &mut self,
-        receiver_id: &str,
-        actions: Vec<Action>,
-    ) -> Result<AccessKeyInfoView, RpcError> {
-        self.find_access_key().await
+        old_public_key: &PublicKey,
+        public_key: &PublicKey,
+    ) -> Result<(), RpcError> {
+        let account_id = self.account_id.clone();
+        let access_key_info = self.get_access_key(public_key).await?;
+        self.send_transaction_with_access_key(&account_id, vec![], access_key_info.clone())
+            .await?;
+
+        self.connection
+            .signer
+            .set_default_key(&account_id, &self.connection.network_id, public_key)
+            .await?;
+
+        let access_key_info = self.get_access_key(public_key).await?;
+        let actions = vec![Action::DeleteKey(DeleteKeyAction {
+            public_key: old_public_key.clone(),
+        })];
+        self.send_transaction_with_access_key(&account_id, actions, access_key_info)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Restores the old key as default and deletes the new key, on-chain first. The new key is
+    /// kept locally when it cannot be deleted on-chain, so it is never lost.
+    async fn rollback_key_rotation(&mut self, old_public_key: &PublicKey, public_key: &PublicKey) {
+        let signer = self.connection.signer.clone();
+        let network_id = self.connection.network_id.clone();
+        let account_id = self.account_id.clone();
+
+        signer
+            .set_default_key(&account_id, &network_id, old_public_key)
+            .await
+            .ok();
+
+        let access_key_info = match self.get_access_key(old_public_key).await {
+            Ok(access_key_info) => access_key_info,
+            Err(_) => return,
+        };
+        let actions = vec![Action::DeleteKey(DeleteKeyAction {
+            public_key: public_key.clone(),
+        })];
+        if self
+            .send_transaction_with_access_key(&account_id, actions, access_key_info)
+            .await
+            .is_ok()
+        {
+            signer
+                .remove_key(&account_id, &network_id, public_key)
+                .await
+                .ok();
+        }
     }
 
     pub async fn create_and_deploy_contract(
@@ -152,12 +419,21 @@ impl Account {
         contract_id: String,
         public_key: PublicKey,
         data: &[u8],
-        amount: Balance,
+        amount: impl Into<NearToken>,
     ) -> Result<Account, RpcError> {
+        let amount = amount.into();
         let access_key = full_access_key();
+        let calculator = self.get_storage_calculator().await?;
+        let storage_usage = calculator
+            .create_account_bytes(&[(public_key.clone(), access_key.clone())])
+            + data.len() as StorageUsage;
+        calculator.check_balance(&contract_id, storage_usage, amount)?;
+
         let actions = vec![
             Action::CreateAccount(CreateAccountAction {}),
-            Action::Transfer(TransferAction { deposit: amount }),
+            Action::Transfer(TransferAction {
+                deposit: amount.as_yoctonear(),
+            }),
             Action::AddKey(AddKeyAction {
                 public_key,
                 access_key,
@@ -177,12 +453,14 @@ impl Account {
     pub async fn send_money(
         &mut self,
         receiver_id: &str,
-        amount: Balance,
+        amount: impl Into<NearToken>,
     ) -> Result<FinalExecutionOutcomeView, RpcError> {
         let result = self
             .sign_and_send_transaction(
                 receiver_id,
-                vec![Action::Transfer(TransferAction { deposit: amount })],
+                vec![Action::Transfer(TransferAction {
+                    deposit: amount.into().as_yoctonear(),
+                })],
             )
             .await?;
 
@@ -193,12 +471,20 @@ impl Account {
         &mut self,
         account_id: &str,
         public_key: PublicKey,
-        amount: Balance,
+        amount: impl Into<NearToken>,
     ) -> Result<FinalExecutionOutcomeView, RpcError> {
+        let amount = amount.into();
         let access_key = full_access_key();
+        let calculator = self.get_storage_calculator().await?;
+        let storage_usage =
+            calculator.create_account_bytes(&[(public_key.clone(), access_key.clone())]);
+        calculator.check_balance(account_id, storage_usage, amount)?;
+
         let actions = vec![
             Action::CreateAccount(CreateAccountAction {}),
-            Action::Transfer(TransferAction { deposit: amount }),
+            Action::Transfer(TransferAction {
+                deposit: amount.as_yoctonear(),
+            }),
             Action::AddKey(AddKeyAction {
                 public_key,
                 access_key,
@@ -232,6 +518,24 @@ impl Account {
         data: &[u8],
     ) -> Result<FinalExecutionOutcomeView, RpcError> {
         let account_id = self.account_id.clone();
+        let calculator = self.get_storage_calculator().await?;
+        let state = self.state().await?;
+        let previous_code_len = match state.code_hash == CryptoHash::default() {
+            true => 0,
+            false => self
+                .near_rpc_user
+                .view_contract_code(&account_id)
+                .await?
+                .code
+                .len(),
+        };
+        let storage_usage = calculator.deploy_contract_bytes(&state, previous_code_len, data);
+        calculator.check_balance(
+            &account_id,
+            storage_usage,
+            NearToken::from_yoctonear(state.amount + state.locked),
+        )?;
+
         let result = self
             .sign_and_send_transaction(
                 &account_id,
@@ -249,22 +553,37 @@ impl Account {
         contract_id: &str,
         method_name: &str,
         args: Vec<u8>,
-        gas: Gas,
-        deposit: Balance,
+        gas: impl Into<NearGas>,
+        deposit: impl Into<NearToken>,
     ) -> Result<FinalExecutionOutcomeView, RpcError> {
-        let result = self
-            .sign_and_send_transaction(
-                contract_id,
-                vec![Action::FunctionCall(FunctionCallAction {
-                    method_name: method_name.to_string(),
-                    args,
-                    gas,
-                    deposit,
-                })],
-            )
+        let outcome = self
+            .function_call_outcome(contract_id, method_name, args, gas, deposit)
             .await?;
 
-        Ok(result)
+        check_outcome(outcome)
+    }
+
+    /// Like `function_call` but a failed call is returned as its outcome, so its logs are kept.
+    pub(crate) async fn function_call_outcome(
+        &mut self,
+        contract_id: &str,
+        method_name: &str,
+        args: Vec<u8>,
+        gas: impl Into<NearGas>,
+        deposit: impl Into<NearToken>,
+    ) -> Result<FinalExecutionOutcomeView, RpcError> {
+        let actions = vec![Action::FunctionCall(FunctionCallAction {
+            method_name: method_name.to_string(),
+            args,
+            gas: gas.into().as_gas(),
+            deposit: deposit.into().as_yoctonear(),
+        })];
+        let access_key_info = self
+            .find_function_access_key(contract_id, actions.clone())
+            .await?;
+
+        self.send_transaction_outcome(contract_id, actions, access_key_info)
+            .await
     }
 
     pub async fn add_key(
@@ -291,9 +610,13 @@ impl Account {
         public_key: PublicKey,
         contract_id: &str,
         method_names: Vec<String>,
-        amount: Option<Balance>,
+        amount: Option<NearToken>,
     ) -> Result<FinalExecutionOutcomeView, RpcError> {
-        let access_key = funcation_call_access_key(contract_id.to_string(), method_names, amount);
+        let access_key = funcation_call_access_key(
+            contract_id.to_string(),
+            method_names,
+            amount.map(Balance::from),
+        );
         let result = self
             .sign_and_send_transaction(
                 contract_id,
@@ -322,10 +645,11 @@ impl Account {
         Ok(result)
     }
 
+    /// Stakes as a validator, delegators stake through a `StakingPool` instead.
     pub async fn stake(
         &mut self,
         public_key: PublicKey,
-        amount: Balance,
+        amount: impl Into<NearToken>,
     ) -> Result<FinalExecutionOutcomeView, RpcError> {
         let account_id = self.account_id.clone();
         let result = self
@@ -333,7 +657,7 @@ impl Account {
                 &account_id,
                 vec![Action::Stake(StakeAction {
                     public_key,
-                    stake: amount,
+                    stake: amount.into().as_yoctonear(),
                 })],
             )
             .await?;
@@ -346,14 +670,17 @@ impl Account {
         contract_id: &str,
         method_name: &str,
         args: Vec<u8>,
-    ) -> Result<CallResult, RpcError> {
-        let account_id = contract_id.parse().unwrap();
-        let view_call_result = self
-            .near_rpc_user
-            .view_call(&account_id, method_name, FunctionArgs(args))
-            .await?;
+    ) -> Result<CallResult, ViewCallError> {
+        let account_id = contract_id
+            .parse::<AccountId>()
+            .map_err(|error| ViewCallError::InvalidAccountId {
+                account_id: contract_id.to_string(),
+                error_message: error.to_string(),
+            })?;
 
-        Ok(view_call_result)
+        self.near_rpc_user
+            .view_call(&account_id, method_name, FunctionArgs(args))
+            .await
     }
 
     pub async fn view_state(&self, prefix: StoreKey) -> Result<ViewStateResult, RpcError> {
@@ -370,50 +697,318 @@ impl Account {
         Ok(access_key_list)
     }
 
+    /// On-chain access key of `public_key`, always fetched so its nonce is current.
+    pub async fn get_access_key(
+        &mut self,
+        public_key: &PublicKey,
+    ) -> Result<AccessKeyInfoView, RpcError> {
+        let item = self
+            .get_access_keys()
+            .await?
+            .keys
+            .into_iter()
+            .find(|item| &item.public_key == public_key)
+            .ok_or_else(|| AccountError::AccessKeyNotFound {
+                account_id: self.account_id.to_string(),
+                public_key: public_key.to_string(),
+            })?;
+
+        self.access_key_by_public_key_cache
+            .insert(item.public_key.to_string(), item.access_key.clone());
+
+        Ok(item)
+    }
+
     pub async fn get_account_details(&self) -> Result<Vec<AccountAuthorizedApp>, RpcError> {
         let access_keys = self.get_access_keys().await?;
         let authorized_apps = access_keys
             .keys
-            .iter()
-            .filter(|item| item.access_key.permission != AccessKeyPermissionView::FullAccess)
-            .map(|item| match item.access_key.permission.clone() {
+            .into_iter()
+            .filter_map(|item| match item.access_key.permission {
                 AccessKeyPermissionView::FunctionCall {
                     allowance,
                     receiver_id,
-                    method_names: _,
-                } => AccountAuthorizedApp {
+                    method_names,
+                } => Some(AccountAuthorizedApp {
                     contract_id: receiver_id.parse().unwrap(),
-                    amount: allowance.unwrap_or(0),
-                    public_key: item.public_key.clone(),
-                },
-                AccessKeyPermissionView::FullAccess => todo!(),
+                    method_names,
+                    allowance: allowance.map(NearToken::from),
+                    public_key: item.public_key,
+                }),
+                AccessKeyPermissionView::FullAccess => None,
             })
             .collect::<Vec<AccountAuthorizedApp>>();
 
         Ok(authorized_apps)
     }
 
-    pub async fn get_account_balance(&self) -> Result<AccountBalance, RpcError> {
+    /// Lists every on-chain access key of the account and flags risky ones.
+    pub async fn audit_access_keys(&self) -> Result<AccountAccessKeyAudit, RpcError> {
+        let access_keys = self.get_access_keys().await?;
+        let local_public_keys = self.get_local_public_keys().await?;
+        let keys = access_keys
+            .keys
+            .into_iter()
+            .map(|item| {
+                let is_local = local_public_keys.contains(&item.public_key);
+                AccountAccessKey::new(item, is_local)
+            })
+            .collect();
+
+        Ok(AccountAccessKeyAudit::new(keys))
+    }
+
+    /// Deletes the keys of `public_keys` in a single transaction, then removes the deleted keys
+    /// from the signer. Refuses to delete every full access key of the account unless
+    /// `allow_deleting_all_full_access_keys` is set, which locks the account.
+    pub async fn delete_keys(
+        &mut self,
+        public_keys: Vec<PublicKey>,
+        allow_deleting_all_full_access_keys: bool,
+    ) -> Result<FinalExecutionOutcomeView, RpcError> {
+        let account_id = self.account_id.clone();
+        if public_keys.is_empty() {
+            return Err(AccountError::NoKeysToDelete {
+                account_id: account_id.to_string(),
+            }
+            .into());
+        }
+
+        let access_keys = self.get_access_keys().await?;
+        if deletes_all_full_access_keys(&access_keys.keys, &public_keys)
+            && !allow_deleting_all_full_access_keys
+        {
+            return Err(AccountError::DeletesAllFullAccessKeys {
+                account_id: account_id.to_string(),
+            }
+            .into());
+        }
+
+        let actions = public_keys
+            .iter()
+            .map(|public_key| {
+                Action::DeleteKey(DeleteKeyAction {
+                    public_key: public_key.clone(),
+                })
+            })
+            .collect();
+        let outcome = self.sign_and_send_transaction(&account_id, actions).await?;
+
+        for public_key in public_keys.iter() {
+            self.access_key_by_public_key_cache
+                .remove(&public_key.to_string());
+        }
+        let local_public_keys = self.get_local_public_keys().await?;
+        for public_key in public_keys
+            .iter()
+            .filter(|public_key| local_public_keys.contains(public_key))
+        {
+            match self
+                .connection
+                .signer
+                .remove_key(&account_id, &self.connection.network_id, public_key)
+                .await
+            {
+                Ok(()) | Err(SignerError::Unsupported { .. }) => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        Ok(outcome)
+    }
+
+    /// Public keys the signer holds for the account, empty when it holds none.
+    async fn get_local_public_keys(&self) -> Result<Vec<PublicKey>, RpcError> {
+        match self
+            .connection
+            .signer
+            .get_public_keys(&self.account_id, &self.connection.network_id)
+            .await
+        {
+            Ok(public_keys) => Ok(public_keys),
+            Err(SignerError::KeyNotFound { .. }) => Ok(vec![]),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Storage prices of the current runtime config.
+    pub async fn get_storage_calculator(&self) -> Result<StorageCalculator, RpcError> {
+        let protocol_config = self
+            .near_rpc_user
+            .get_protocol_config(BlockReference::Finality(Finality::Final))
+            .await?;
+
+        Ok(StorageCalculator::new(&protocol_config.runtime_config))
+    }
+
+    /// Current epoch with the average block time since it started.
+    pub async fn get_epoch_info(&self) -> Result<EpochInfo, RpcError> {
+        let validators = self.near_rpc_user.get_validators(None).await?;
         let protocol_config = self
             .near_rpc_user
             .get_protocol_config(BlockReference::Finality(Finality::Final))
             .await?;
+        let epoch_start = self
+            .near_rpc_user
+            .get_block(BlockReference::BlockId(BlockId::Height(
+                validators.epoch_start_height,
+            )))
+            .await?;
+        let latest = self.near_rpc_user.get_block(BlockReference::latest()).await?;
+
+        Ok(EpochInfo::new(
+            &validators,
+            protocol_config.epoch_length,
+            &epoch_start.header,
+            &latest.header,
+        ))
+    }
+
+    /// Bytes more the account can store with its current balance.
+    pub async fn get_available_storage(&self) -> Result<StorageUsage, RpcError> {
+        let calculator = self.get_storage_calculator().await?;
         let state = self.state().await?;
 
-        let cost_per_byte = protocol_config.runtime_config.storage_amount_per_byte;
-        let state_staked = state.storage_usage as u128 * cost_per_byte;
-        let staked = state.locked;
-        let total = state.amount + staked;
-        let available = match staked > state_staked {
-            true => total / staked,
-            false => total / state_staked,
+        Ok(calculator.available_bytes(&state))
+    }
+
+    pub async fn get_account_balance(&self) -> Result<AccountBalance, RpcError> {
+        self.get_account_balance_at(BlockReference::Finality(Finality::Final))
+            .await
+    }
+
+    /// Balance of the account at `block_reference`, storage is priced with the runtime config
+    /// of the same block.
+    pub async fn get_account_balance_at(
+        &self,
+        block_reference: BlockReference,
+    ) -> Result<AccountBalance, RpcError> {
+        let protocol_config = self
+            .near_rpc_user
+            .get_protocol_config(block_reference.clone())
+            .await?;
+        let state = self
+            .near_rpc_user
+            .view_account_at(&self.account_id, block_reference)
+            .await?;
+
+        Ok(AccountBalance::new(
+            state.amount,
+            state.locked,
+            state.storage_usage,
+            protocol_config.runtime_config.storage_amount_per_byte,
+        ))
+    }
+}
+
+/// Turns a failed transaction outcome into an error.
+fn check_outcome(
+    outcome: FinalExecutionOutcomeView,
+) -> Result<FinalExecutionOutcomeView, RpcError> {
+    match &outcome.status {
+        FinalExecutionStatus::Failure(error) => Err(AccountError::TransactionFailed {
+            error_message: error.to_string(),
+        }
+        .into()),
+        _ => Ok(outcome),
+    }
+}
+
+/// Whether deleting `public_keys` leaves an account holding full access keys without any.
+fn deletes_all_full_access_keys(
+    access_keys: &[AccessKeyInfoView],
+    public_keys: &[PublicKey],
+) -> bool {
+    let mut full_access_keys = access_keys
+        .iter()
+        .filter(|item| item.access_key.permission == AccessKeyPermissionView::FullAccess)
+        .peekable();
+
+    full_access_keys.peek().is_some()
+        && full_access_keys.all(|item| public_keys.contains(&item.public_key))
+}
+
+/// Whether a key with `permission` can call `method_name` on `receiver_id`.
+fn permission_allows(
+    permission: &AccessKeyPermissionView,
+    receiver_id: &str,
+    method_name: &str,
+) -> bool {
+    match permission {
+        AccessKeyPermissionView::FullAccess => true,
+        AccessKeyPermissionView::FunctionCall {
+            receiver_id: key_receiver_id,
+            method_names,
+            ..
+        } => {
+            key_receiver_id == receiver_id
+                && (method_names.is_empty() || method_names.iter().any(|item| item == method_name))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::key_pair::KeyPair};
+
+    #[test]
+    pub fn test_permission_allows() {
+        let permission = AccessKeyPermissionView::FunctionCall {
+            allowance: None,
+            receiver_id: "app.near".to_string(),
+            method_names: vec!["vote".to_string()],
+        };
+
+        assert!(permission_allows(&permission, "app.near", "vote"));
+        assert!(!permission_allows(&permission, "app.near", "withdraw"));
+        assert!(!permission_allows(&permission, "other.near", "vote"));
+
+        let permission = AccessKeyPermissionView::FunctionCall {
+            allowance: None,
+            receiver_id: "app.near".to_string(),
+            method_names: vec![],
         };
+        assert!(permission_allows(&permission, "app.near", "withdraw"));
+        assert!(permission_allows(
+            &AccessKeyPermissionView::FullAccess,
+            "other.near",
+            "withdraw"
+        ));
+    }
+
+    #[test]
+    pub fn test_deletes_all_full_access_keys() {
+        let access_key = |permission| AccessKeyInfoView {
+            public_key: KeyPair::from_random(KeyType::ED25519).public_key(),
+            access_key: AccessKeyView {
+                nonce: 0,
+                permission,
+            },
+        };
+        let full_access_key = access_key(AccessKeyPermissionView::FullAccess);
+        let other_full_access_key = access_key(AccessKeyPermissionView::FullAccess);
+        let function_call_key = access_key(AccessKeyPermissionView::FunctionCall {
+            allowance: None,
+            receiver_id: "app.near".to_string(),
+            method_names: vec![],
+        });
+        let access_keys = vec![
+            full_access_key.clone(),
+            other_full_access_key.clone(),
+            function_call_key.clone(),
+        ];
 
-        Ok(AccountBalance {
-            total,
-            state_staked,
-            staked,
-            available,
-        })
+        assert!(!deletes_all_full_access_keys(
+            &access_keys,
+            core::slice::from_ref(&full_access_key.public_key)
+        ));
+        assert!(deletes_all_full_access_keys(
+            &access_keys,
+            &[full_access_key.public_key, other_full_access_key.public_key]
+        ));
+        assert!(!deletes_all_full_access_keys(
+            &access_keys[2..],
+            &[function_call_key.public_key]
+        ));
     }
 }
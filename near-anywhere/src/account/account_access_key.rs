@@ -0,0 +1,171 @@
+use crate::{
+    crypto::PublicKey,
+    primitives::{
+        access_key::{AccessKeyInfoView, AccessKeyPermissionView},
+        types::{NearToken, Nonce},
+    },
+};
+
+/// More full access keys than this are flagged by the audit.
+pub const MAX_FULL_ACCESS_KEYS: usize = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessKeyKind {
+    FullAccess,
+    FunctionCall,
+}
+
+/// An on-chain access key of the account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountAccessKey {
+    pub public_key: PublicKey,
+    pub kind: AccessKeyKind,
+    pub nonce: Nonce,
+    /// Contract the key can call, `None` for full access keys.
+    pub receiver_id: Option<String>,
+    /// Methods the key can call, empty for any method.
+    pub method_names: Vec<String>,
+    /// Remaining allowance for gas fees, `None` when unlimited.
+    pub allowance: Option<NearToken>,
+    /// Whether the key is in the local key store.
+    pub is_local: bool,
+}
+
+impl AccountAccessKey {
+    pub fn new(item: AccessKeyInfoView, is_local: bool) -> Self {
+        let nonce = item.access_key.nonce;
+        let (kind, receiver_id, method_names, allowance) = match item.access_key.permission {
+            AccessKeyPermissionView::FullAccess => (AccessKeyKind::FullAccess, None, vec![], None),
+            AccessKeyPermissionView::FunctionCall {
+                allowance,
+                receiver_id,
+                method_names,
+            } => (
+                AccessKeyKind::FunctionCall,
+                Some(receiver_id),
+                method_names,
+                allowance.map(NearToken::from),
+            ),
+        };
+
+        Self {
+            public_key: item.public_key,
+            kind,
+            nonce,
+            receiver_id,
+            method_names,
+            allowance,
+            is_local,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AccessKeyRisk {
+    /// More than `MAX_FULL_ACCESS_KEYS` full access keys can control the account.
+    TooManyFullAccessKeys { count: usize },
+    /// The key is on-chain but not in the local key store.
+    KeyNotLocal {
+        public_key: PublicKey,
+        kind: AccessKeyKind,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountAccessKeyAudit {
+    pub keys: Vec<AccountAccessKey>,
+    pub risks: Vec<AccessKeyRisk>,
+}
+
+impl AccountAccessKeyAudit {
+    pub fn new(keys: Vec<AccountAccessKey>) -> Self {
+        let mut risks = vec![];
+        let count = keys
+            .iter()
+            .filter(|key| key.kind == AccessKeyKind::FullAccess)
+            .count();
+
+        if count > MAX_FULL_ACCESS_KEYS {
+            risks.push(AccessKeyRisk::TooManyFullAccessKeys { count });
+        }
+
+        for key in keys.iter().filter(|key| !key.is_local) {
+            risks.push(AccessKeyRisk::KeyNotLocal {
+                public_key: key.public_key.clone(),
+                kind: key.kind.clone(),
+            });
+        }
+
+        Self { keys, risks }
+    }
+
+    pub fn full_access_keys(&self) -> impl Iterator<Item = &AccountAccessKey> {
+        self.keys
+            .iter()
+            .filter(|key| key.kind == AccessKeyKind::FullAccess)
+    }
+
+    pub fn function_call_keys(&self) -> impl Iterator<Item = &AccountAccessKey> {
+        self.keys
+            .iter()
+            .filter(|key| key.kind == AccessKeyKind::FunctionCall)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{crypto::KeyType, key_pair::KeyPair, primitives::access_key::AccessKeyView},
+    };
+
+    fn access_key(permission: AccessKeyPermissionView, is_local: bool) -> AccountAccessKey {
+        let item = AccessKeyInfoView {
+            public_key: KeyPair::from_random(KeyType::ED25519).public_key(),
+            access_key: AccessKeyView {
+                nonce: 1,
+                permission,
+            },
+        };
+
+        AccountAccessKey::new(item, is_local)
+    }
+
+    #[test]
+    pub fn test_audit() {
+        let function_call = AccessKeyPermissionView::FunctionCall {
+            allowance: Some(250),
+            receiver_id: "app.near".to_string(),
+            method_names: vec!["vote".to_string()],
+        };
+        let keys = vec![
+            access_key(AccessKeyPermissionView::FullAccess, true),
+            access_key(function_call, true),
+        ];
+        let audit = AccountAccessKeyAudit::new(keys);
+
+        assert!(audit.risks.is_empty());
+        let key = audit.function_call_keys().next().unwrap();
+        assert_eq!(key.receiver_id.as_deref(), Some("app.near"));
+        assert_eq!(key.method_names, vec!["vote".to_string()]);
+        assert_eq!(key.allowance, Some(NearToken::from_yoctonear(250)));
+
+        let keys = vec![
+            access_key(AccessKeyPermissionView::FullAccess, true),
+            access_key(AccessKeyPermissionView::FullAccess, false),
+        ];
+        let not_local = keys[1].public_key.clone();
+        let audit = AccountAccessKeyAudit::new(keys);
+
+        assert_eq!(
+            audit.risks,
+            vec![
+                AccessKeyRisk::TooManyFullAccessKeys { count: 2 },
+                AccessKeyRisk::KeyNotLocal {
+                    public_key: not_local,
+                    kind: AccessKeyKind::FullAccess,
+                },
+            ]
+        );
+    }
+}
@@ -1,11 +1,13 @@
 use crate::{
     crypto::PublicKey,
-    primitives::types::{AccountId, Balance},
+    primitives::types::{AccountId, NearToken},
 };
 
 #[derive(Clone)]
 pub struct AccountAuthorizedApp {
     pub contract_id: AccountId,
-    pub amount: Balance,
+    pub method_names: Vec<String>,
+    /// Remaining allowance for gas fees, `None` when unlimited.
+    pub allowance: Option<NearToken>,
     pub public_key: PublicKey,
 }
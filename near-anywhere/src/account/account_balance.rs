@@ -1,9 +1,78 @@
-use crate::primitives::types::Balance;
+use crate::primitives::types::{Balance, NearToken, StorageUsage};
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AccountBalance {
-    pub total: Balance,
-    pub state_staked: Balance,
-    pub staked: Balance,
-    pub available: Balance,
+    /// Liquid amount plus locked stake.
+    pub total: NearToken,
+    /// Amount reserved for the storage used by the account.
+    pub state_staked: NearToken,
+    /// Locked stake, it also covers storage.
+    pub staked: NearToken,
+    /// Amount that can be spent, storage cost not covered by the locked stake is kept.
+    pub available: NearToken,
+    /// Bytes of storage used by the account.
+    pub storage_usage: StorageUsage,
+    pub storage_amount_per_byte: NearToken,
+}
+
+impl AccountBalance {
+    pub fn new(
+        amount: Balance,
+        locked: Balance,
+        storage_usage: StorageUsage,
+        storage_amount_per_byte: Balance,
+    ) -> Self {
+        let state_staked = storage_usage as u128 * storage_amount_per_byte;
+        let total = amount + locked;
+        let available = total.saturating_sub(locked.max(state_staked));
+
+        Self {
+            total: total.into(),
+            state_staked: state_staked.into(),
+            staked: locked.into(),
+            available: available.into(),
+            storage_usage,
+            storage_amount_per_byte: storage_amount_per_byte.into(),
+        }
+    }
+
+    /// The amounts formatted in NEAR rounded to `decimals` places.
+    pub fn format_near(&self, decimals: usize) -> FormattedAccountBalance {
+        FormattedAccountBalance {
+            total: self.total.format(decimals),
+            state_staked: self.state_staked.format(decimals),
+            staked: self.staked.format(decimals),
+            available: self.available.format(decimals),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormattedAccountBalance {
+    pub total: String,
+    pub state_staked: String,
+    pub staked: String,
+    pub available: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_account_balance() {
+        let near = NearToken::from_near(1).as_yoctonear();
+        let balance = AccountBalance::new(5 * near, 0, 1_000, 10u128.pow(19));
+
+        assert_eq!(balance.total, NearToken::from_near(5));
+        assert_eq!(balance.state_staked, NearToken::from_millinear(10));
+        assert_eq!(balance.available, NearToken::from_millinear(4_990));
+
+        // Storage is covered by the locked stake.
+        let balance = AccountBalance::new(5 * near, near, 1_000, 10u128.pow(19));
+
+        assert_eq!(balance.total, NearToken::from_near(6));
+        assert_eq!(balance.available, NearToken::from_near(5));
+        assert_eq!(balance.format_near(2).state_staked, "0.01");
+    }
 }
@@ -0,0 +1,87 @@
+use {
+    crate::primitives::{
+        block::BlockHeaderView,
+        network::EpochValidatorInfo,
+        types::{BlockHeight, BlockHeightDelta, EpochHeight},
+    },
+    core::time::Duration,
+};
+
+/// Assumed block time when the current epoch has too few blocks to measure it.
+const DEFAULT_BLOCK_TIME: Duration = Duration::from_secs(1);
+
+/// Position in the current epoch, used to estimate when a later epoch starts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EpochInfo {
+    pub epoch_height: EpochHeight,
+    pub epoch_start_height: BlockHeight,
+    pub epoch_length: BlockHeightDelta,
+    pub block_height: BlockHeight,
+    /// Average block time since the epoch started.
+    pub block_time: Duration,
+}
+
+impl EpochInfo {
+    pub fn new(
+        validators: &EpochValidatorInfo,
+        epoch_length: BlockHeightDelta,
+        epoch_start: &BlockHeaderView,
+        latest: &BlockHeaderView,
+    ) -> Self {
+        let blocks = latest.height.saturating_sub(epoch_start.height);
+        let block_time = match blocks {
+            0 => DEFAULT_BLOCK_TIME,
+            _ => Duration::from_nanos(
+                latest.timestamp.saturating_sub(epoch_start.timestamp) / blocks,
+            ),
+        };
+
+        Self {
+            epoch_height: validators.epoch_height,
+            epoch_start_height: validators.epoch_start_height,
+            epoch_length,
+            block_height: latest.height,
+            block_time,
+        }
+    }
+
+    /// Blocks until `epoch_height` starts, zero when it has started already.
+    pub fn blocks_until_epoch(&self, epoch_height: EpochHeight) -> BlockHeightDelta {
+        let epochs = epoch_height.saturating_sub(self.epoch_height);
+        let start_height = self.epoch_start_height + epochs * self.epoch_length;
+
+        match epochs {
+            0 => 0,
+            _ => start_height.saturating_sub(self.block_height),
+        }
+    }
+
+    /// Time until `epoch_height` starts at the current average block time.
+    pub fn time_until_epoch(&self, epoch_height: EpochHeight) -> Duration {
+        let nanos = self.block_time.as_nanos() as u64;
+
+        Duration::from_nanos(nanos.saturating_mul(self.blocks_until_epoch(epoch_height)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_time_until_epoch() {
+        let epoch_info = EpochInfo {
+            epoch_height: 10,
+            epoch_start_height: 1_000,
+            epoch_length: 100,
+            block_height: 1_040,
+            block_time: Duration::from_millis(1_200),
+        };
+
+        assert_eq!(epoch_info.blocks_until_epoch(9), 0);
+        assert_eq!(epoch_info.blocks_until_epoch(10), 0);
+        assert_eq!(epoch_info.blocks_until_epoch(11), 60);
+        assert_eq!(epoch_info.blocks_until_epoch(14), 360);
+        assert_eq!(epoch_info.time_until_epoch(11), Duration::from_secs(72));
+    }
+}
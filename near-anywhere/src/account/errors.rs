@@ -0,0 +1,48 @@
+use crate::primitives::types::NearToken;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AccountError {
+    #[error("no full access key of '{account_id}' found")]
+    FullAccessKeyNotFound { account_id: String },
+    #[error("no access key of '{account_id}' can call '{method_name}' on '{receiver_id}'")]
+    FunctionCallKeyNotFound {
+        account_id: String,
+        receiver_id: String,
+        method_name: String,
+    },
+    #[error("allowance {allowance} of key '{public_key}' does not cover {required} for gas")]
+    InsufficientAllowance {
+        public_key: String,
+        allowance: u128,
+        required: u128,
+    },
+    #[error("access key '{public_key}' of '{account_id}' not found")]
+    AccessKeyNotFound {
+        account_id: String,
+        public_key: String,
+    },
+    #[error(
+        "'{account_id}' lacks {shortfall} for storage, {required} needed but {balance} available"
+    )]
+    LackBalanceForState {
+        account_id: String,
+        required: NearToken,
+        balance: NearToken,
+        shortfall: NearToken,
+    },
+    #[error("no keys of '{account_id}' to delete")]
+    NoKeysToDelete { account_id: String },
+    #[error("deleting every full access key of '{account_id}' locks the account")]
+    DeletesAllFullAccessKeys { account_id: String },
+    #[error("transaction failed: {error_message}")]
+    TransactionFailed { error_message: String },
+}
+
+impl From<AccountError> for crate::client::RpcError {
+    fn from(error: AccountError) -> Self {
+        Self {
+            code: 0,
+            message: error.to_string(),
+        }
+    }
+}
@@ -4,13 +4,27 @@ use crate::primitives::{
 };
 
 mod account;
+mod account_access_key;
 mod account_authorized_app;
 mod account_balance;
+mod epoch_info;
+mod errors;
+mod storage_calculator;
 
 pub use {
     account::Account,
+    account_access_key::{
+        AccessKeyKind,
+        AccessKeyRisk,
+        AccountAccessKey,
+        AccountAccessKeyAudit,
+        MAX_FULL_ACCESS_KEYS,
+    },
     account_authorized_app::AccountAuthorizedApp,
-    account_balance::AccountBalance,
+    account_balance::{AccountBalance, FormattedAccountBalance},
+    epoch_info::EpochInfo,
+    errors::AccountError,
+    storage_calculator::StorageCalculator,
 };
 
 pub fn full_access_key() -> AccessKey {
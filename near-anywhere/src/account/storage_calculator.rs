@@ -0,0 +1,204 @@
+use {
+    super::AccountError,
+    crate::{
+        crypto::PublicKey,
+        primitives::{
+            account::{AccessKey, AccountView},
+            config::RuntimeConfig,
+            runtime::fees::StorageUsageConfig,
+            types::{Balance, NearToken, StorageUsage},
+        },
+    },
+    borsh::BorshSerialize,
+};
+
+/// Prices account storage the way the runtime does, so a lack of balance for state is caught
+/// before a transaction is sent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageCalculator {
+    pub storage_amount_per_byte: Balance,
+    pub storage_usage_config: StorageUsageConfig,
+}
+
+impl StorageCalculator {
+    pub fn new(runtime_config: &RuntimeConfig) -> Self {
+        Self {
+            storage_amount_per_byte: runtime_config.storage_amount_per_byte,
+            storage_usage_config: runtime_config
+                .transaction_costs
+                .storage_usage_config
+                .clone(),
+        }
+    }
+
+    /// Saturates, a cost past `Balance::MAX` can never be covered anyway.
+    pub fn storage_cost(&self, storage_usage: StorageUsage) -> NearToken {
+        NearToken::from_yoctonear(
+            (storage_usage as Balance)
+                .checked_mul(self.storage_amount_per_byte)
+                .unwrap_or(Balance::MAX),
+        )
+    }
+
+    /// Bytes more the account can store with its current balance, zero without a storage price.
+    pub fn available_bytes(&self, account: &AccountView) -> StorageUsage {
+        let storable = account
+            .amount
+            .saturating_add(account.locked)
+            .checked_div(self.storage_amount_per_byte)
+            .unwrap_or(0);
+
+        StorageUsage::try_from(storable)
+            .unwrap_or(StorageUsage::MAX)
+            .saturating_sub(account.storage_usage)
+    }
+
+    /// Storage used by an access key, its record overhead included.
+    pub fn access_key_bytes(&self, public_key: &PublicKey, access_key: &AccessKey) -> StorageUsage {
+        let public_key = public_key.try_to_vec().unwrap().len();
+        let access_key = access_key.try_to_vec().unwrap().len();
+
+        (public_key + access_key) as StorageUsage + self.storage_usage_config.num_extra_bytes_record
+    }
+
+    /// Storage used by a new account holding `access_keys`.
+    pub fn create_account_bytes(&self, access_keys: &[(PublicKey, AccessKey)]) -> StorageUsage {
+        access_keys
+            .iter()
+            .map(|(public_key, access_key)| self.access_key_bytes(public_key, access_key))
+            .sum::<StorageUsage>()
+            + self.storage_usage_config.num_bytes_account
+    }
+
+    /// Least balance `create_account` must transfer for a new account holding `access_keys`.
+    pub fn create_account_cost(&self, access_keys: &[(PublicKey, AccessKey)]) -> NearToken {
+        self.storage_cost(self.create_account_bytes(access_keys))
+    }
+
+    /// Storage used by the account once `code` replaces its contract of `previous_code_len`
+    /// bytes.
+    pub fn deploy_contract_bytes(
+        &self,
+        account: &AccountView,
+        previous_code_len: usize,
+        code: &[u8],
+    ) -> StorageUsage {
+        account
+            .storage_usage
+            .saturating_sub(previous_code_len as StorageUsage)
+            + code.len() as StorageUsage
+    }
+
+    /// Balance the account needs to cover its storage once `code` is deployed.
+    pub fn deploy_contract_cost(
+        &self,
+        account: &AccountView,
+        previous_code_len: usize,
+        code: &[u8],
+    ) -> NearToken {
+        self.storage_cost(self.deploy_contract_bytes(account, previous_code_len, code))
+    }
+
+    /// Fails with the shortfall when `balance` does not cover `storage_usage` bytes.
+    pub fn check_balance(
+        &self,
+        account_id: &str,
+        storage_usage: StorageUsage,
+        balance: NearToken,
+    ) -> Result<(), AccountError> {
+        let required = self.storage_cost(storage_usage);
+
+        match required.checked_sub(balance) {
+            Some(shortfall) if !shortfall.is_zero() => Err(AccountError::LackBalanceForState {
+                account_id: account_id.to_string(),
+                required,
+                balance,
+                shortfall,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{account::full_access_key, crypto::KeyType, key_pair::KeyPair},
+    };
+
+    fn calculator() -> StorageCalculator {
+        StorageCalculator {
+            storage_amount_per_byte: 10u128.pow(19),
+            storage_usage_config: StorageUsageConfig {
+                num_bytes_account: 100,
+                num_extra_bytes_record: 40,
+            },
+        }
+    }
+
+    #[test]
+    pub fn test_create_account_cost() {
+        let calculator = calculator();
+        let public_key = KeyPair::from_random(KeyType::ED25519).public_key();
+        let access_keys = vec![(public_key, full_access_key())];
+
+        // 33 bytes of public key, 9 bytes of access key and 40 bytes of record overhead.
+        assert_eq!(calculator.create_account_bytes(&access_keys), 182);
+        assert_eq!(
+            calculator.create_account_cost(&access_keys),
+            NearToken::from_yoctonear(182 * 10u128.pow(19))
+        );
+    }
+
+    #[test]
+    pub fn test_check_balance() {
+        let calculator = calculator();
+        let account = AccountView {
+            amount: NearToken::from_millinear(5).as_yoctonear(),
+            locked: 0,
+            code_hash: Default::default(),
+            storage_usage: 200,
+            storage_paid_at: 0,
+        };
+
+        assert_eq!(calculator.available_bytes(&account), 300);
+
+        let storage_usage = calculator.deploy_contract_bytes(&account, 0, &[0; 1_000]);
+        assert_eq!(storage_usage, 1_200);
+        assert!(matches!(
+            calculator.check_balance("alice.near", storage_usage, NearToken::from_millinear(5)),
+            Err(AccountError::LackBalanceForState { shortfall, .. })
+                if shortfall == NearToken::from_millinear(7)
+        ));
+        assert!(calculator
+            .check_balance("alice.near", 500, NearToken::from_millinear(5))
+            .is_ok());
+    }
+
+    #[test]
+    pub fn test_overflows() {
+        let account = AccountView {
+            amount: Balance::MAX,
+            locked: Balance::MAX,
+            code_hash: Default::default(),
+            storage_usage: 200,
+            storage_paid_at: 0,
+        };
+        let mut calculator = calculator();
+
+        assert_eq!(
+            calculator.available_bytes(&account),
+            StorageUsage::MAX - 200
+        );
+        calculator.storage_amount_per_byte = 10u128.pow(20);
+        assert_eq!(
+            calculator.storage_cost(StorageUsage::MAX),
+            NearToken::from_yoctonear(Balance::MAX)
+        );
+
+        calculator.storage_amount_per_byte = 0;
+        assert_eq!(calculator.available_bytes(&account), 0);
+        assert_eq!(calculator.storage_cost(200), NearToken::from_yoctonear(0));
+    }
+}
@@ -1,12 +1,12 @@
-use crate::{account::Account, crypto::PublicKey, primitives::types::Balance};
+use crate::{account::Account, crypto::PublicKey, primitives::types::NearToken};
 
 pub struct LocalAccountCreator {
     master_account: Account,
-    initial_balance: Balance,
+    initial_balance: NearToken,
 }
 
 impl LocalAccountCreator {
-    pub fn new(master_account: Account, initial_balance: Balance) -> Self {
+    pub fn new(master_account: Account, initial_balance: NearToken) -> Self {
         Self {
             master_account,
             initial_balance,
@@ -15,7 +15,7 @@ impl LocalAccountCreator {
 
     pub async fn create_account(&mut self, account_id: &str, public_key: PublicKey) {
         self.master_account
-            .create_account(account_id, public_key, self.initial_balance.clone())
+            .create_account(account_id, public_key, self.initial_balance)
             .await
             .unwrap();
     }
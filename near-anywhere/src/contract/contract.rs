@@ -0,0 +1,149 @@
+use {
+    super::{ContractError, ContractResult, Deserializer, Json, Serializer},
+    crate::{
+        account::Account,
+        primitives::{
+            query::ViewCallError,
+            transaction::{FinalExecutionOutcomeView, FinalExecutionStatus},
+            types::{NearGas, NearToken},
+        },
+        serialize::from_base64,
+    },
+    core::marker::PhantomData,
+};
+
+/// A contract called through an account, arguments and return values are encoded with `S`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contract<S = Json> {
+    account: Account,
+    contract_id: String,
+    serializer: PhantomData<S>,
+}
+
+impl Contract<Json> {
+    pub fn new(account: Account, contract_id: &str) -> Self {
+        Self {
+            account,
+            contract_id: contract_id.to_string(),
+            serializer: PhantomData,
+        }
+    }
+}
+
+impl<S> Contract<S> {
+    /// Switches the encoding, e.g. to `Borsh`.
+    pub fn with_serializer<T>(self) -> Contract<T> {
+        Contract {
+            account: self.account,
+            contract_id: self.contract_id,
+            serializer: PhantomData,
+        }
+    }
+
+    pub fn contract_id(&self) -> &str {
+        &self.contract_id
+    }
+
+    pub fn account(&self) -> &Account {
+        &self.account
+    }
+
+    pub async fn view<A: ?Sized, R>(
+        &self,
+        method_name: &str,
+        args: &A,
+    ) -> Result<ContractResult<R>, ContractError>
+    where
+        S: Serializer<A> + Deserializer<R>,
+    {
+        let args = serialize_args::<S, A>(method_name, args)?;
+        let result = self
+            .account
+            .view_function(&self.contract_id, method_name, args)
+            .await
+            .map_err(|error| match error {
+                ViewCallError::Execution { error, logs } => ContractError::Execution {
+                    method_name: method_name.to_string(),
+                    error_message: error,
+                    logs,
+                },
+                error => ContractError::Rpc {
+                    error_message: error.to_string(),
+                },
+            })?;
+        let value = deserialize_value::<S, R>(method_name, &result.result)?;
+
+        Ok(ContractResult {
+            value,
+            logs: result.logs,
+        })
+    }
+
+    pub async fn call<A: ?Sized, R>(
+        &mut self,
+        method_name: &str,
+        args: &A,
+        gas: impl Into<NearGas>,
+        deposit: impl Into<NearToken>,
+    ) -> Result<ContractResult<R>, ContractError>
+    where
+        S: Serializer<A> + Deserializer<R>,
+    {
+        let args = serialize_args::<S, A>(method_name, args)?;
+        let outcome = self
+            .account
+            .function_call_outcome(&self.contract_id, method_name, args, gas, deposit)
+            .await?;
+        let logs = outcome_logs(&outcome);
+
+        match outcome.status {
+            FinalExecutionStatus::SuccessValue(value) => {
+                let bytes = from_base64(&value).map_err(|error| ContractError::Deserialize {
+                    method_name: method_name.to_string(),
+                    error_message: error.to_string(),
+                })?;
+                let value = deserialize_value::<S, R>(method_name, &bytes)?;
+
+                Ok(ContractResult { value, logs })
+            }
+            FinalExecutionStatus::Failure(error) => Err(ContractError::Execution {
+                method_name: method_name.to_string(),
+                error_message: error.to_string(),
+                logs,
+            }),
+            status => Err(ContractError::Execution {
+                method_name: method_name.to_string(),
+                error_message: format!("unexpected status {:?}", status),
+                logs,
+            }),
+        }
+    }
+}
+
+fn serialize_args<S: Serializer<A>, A: ?Sized>(
+    method_name: &str,
+    args: &A,
+) -> Result<Vec<u8>, ContractError> {
+    S::serialize(args).map_err(|error_message| ContractError::Serialize {
+        method_name: method_name.to_string(),
+        error_message,
+    })
+}
+
+fn deserialize_value<S: Deserializer<R>, R>(
+    method_name: &str,
+    bytes: &[u8],
+) -> Result<R, ContractError> {
+    S::deserialize(bytes).map_err(|error_message| ContractError::Deserialize {
+        method_name: method_name.to_string(),
+        error_message,
+    })
+}
+
+/// Logs of the transaction and of every receipt, in execution order.
+fn outcome_logs(outcome: &FinalExecutionOutcomeView) -> Vec<String> {
+    core::iter::once(&outcome.transaction_outcome)
+        .chain(outcome.receipts_outcome.iter())
+        .flat_map(|item| item.outcome.logs.iter().cloned())
+        .collect()
+}
@@ -0,0 +1,6 @@
+/// Decoded return value of a contract method with the logs it emitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractResult<T> {
+    pub value: T,
+    pub logs: Vec<String>,
+}
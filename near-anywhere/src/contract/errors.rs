@@ -0,0 +1,29 @@
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ContractError {
+    #[error("failed to serialize the arguments of '{method_name}': {error_message}")]
+    Serialize {
+        method_name: String,
+        error_message: String,
+    },
+    #[error("failed to deserialize the result of '{method_name}': {error_message}")]
+    Deserialize {
+        method_name: String,
+        error_message: String,
+    },
+    #[error("'{method_name}' failed: {error_message}")]
+    Execution {
+        method_name: String,
+        error_message: String,
+        logs: Vec<String>,
+    },
+    #[error("{error_message}")]
+    Rpc { error_message: String },
+}
+
+impl From<crate::client::RpcError> for ContractError {
+    fn from(error: crate::client::RpcError) -> Self {
+        Self::Rpc {
+            error_message: error.message,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::serialize::u128_dec_format;
+
+/// A `u128` encoded as a decimal string, as contracts pass token amounts in JSON.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct U128(#[serde(with = "u128_dec_format")] pub u128);
+
+impl From<u128> for U128 {
+    fn from(value: u128) -> Self {
+        Self(value)
+    }
+}
+
+impl From<U128> for u128 {
+    fn from(value: U128) -> Self {
+        value.0
+    }
+}
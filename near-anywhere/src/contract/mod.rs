@@ -0,0 +1,22 @@
+use crate::primitives::types::NearToken;
+
+mod contract;
+mod contract_result;
+mod errors;
+mod json_types;
+mod serializer;
+
+pub use {
+    contract::Contract,
+    contract_result::ContractResult,
+    errors::ContractError,
+    json_types::U128,
+    serializer::{Borsh, Deserializer, Json, Serializer},
+};
+
+/// Deposit that NEP standards require to confirm calls moving assets, it needs a full access key.
+pub const ONE_YOCTO: NearToken = NearToken::from_yoctonear(1);
+
+/// Used by the clients generated by `near-anywhere-macros`.
+#[doc(hidden)]
+pub use serde_json;
@@ -0,0 +1,72 @@
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    serde::{de::DeserializeOwned, Serialize},
+};
+
+/// Encodes contract arguments of type `T`.
+pub trait Serializer<T: ?Sized> {
+    fn serialize(value: &T) -> Result<Vec<u8>, String>;
+}
+
+/// Decodes contract return values of type `T`.
+pub trait Deserializer<T> {
+    fn deserialize(bytes: &[u8]) -> Result<T, String>;
+}
+
+/// JSON encoding, used by most contracts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Json;
+
+impl<T: Serialize + ?Sized> Serializer<T> for Json {
+    fn serialize(value: &T) -> Result<Vec<u8>, String> {
+        serde_json::to_vec(value).map_err(|error| error.to_string())
+    }
+}
+
+impl<T: DeserializeOwned> Deserializer<T> for Json {
+    /// Methods returning nothing decode as `null`.
+    fn deserialize(bytes: &[u8]) -> Result<T, String> {
+        let bytes = match bytes.is_empty() {
+            true => b"null".as_slice(),
+            false => bytes,
+        };
+
+        serde_json::from_slice(bytes).map_err(|error| error.to_string())
+    }
+}
+
+/// Borsh encoding, for contracts with `#[serializer(borsh)]` methods.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Borsh;
+
+impl<T: BorshSerialize + ?Sized> Serializer<T> for Borsh {
+    fn serialize(value: &T) -> Result<Vec<u8>, String> {
+        value.try_to_vec().map_err(|error| error.to_string())
+    }
+}
+
+impl<T: BorshDeserialize> Deserializer<T> for Borsh {
+    fn deserialize(bytes: &[u8]) -> Result<T, String> {
+        T::try_from_slice(bytes).map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_serializers() {
+        let bytes = <Json as Serializer<Vec<u32>>>::serialize(&vec![1, 2]).unwrap();
+        assert_eq!(bytes, b"[1,2]");
+        assert_eq!(
+            <Json as Deserializer<Vec<u32>>>::deserialize(&bytes).unwrap(),
+            vec![1, 2]
+        );
+        assert!(<Json as Deserializer<()>>::deserialize(&[]).is_ok());
+
+        let bytes = <Borsh as Serializer<u32>>::serialize(&7).unwrap();
+        assert_eq!(bytes, vec![7, 0, 0, 0]);
+        assert_eq!(<Borsh as Deserializer<u32>>::deserialize(&bytes).unwrap(), 7);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::contract::ContractError;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum FungibleTokenError {
+    #[error("'{account_id}' is not registered with '{contract_id}'")]
+    NotRegistered {
+        contract_id: String,
+        account_id: String,
+    },
+    #[error("{error}")]
+    Contract { error: ContractError },
+}
+
+impl From<ContractError> for FungibleTokenError {
+    fn from(error: ContractError) -> Self {
+        Self::Contract { error }
+    }
+}
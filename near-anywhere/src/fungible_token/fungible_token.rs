@@ -0,0 +1,134 @@
+use {
+    super::{FungibleTokenError, FungibleTokenMetadata},
+    crate::{
+        account::Account,
+        contract::{Contract, ContractResult, ONE_YOCTO, U128},
+        primitives::types::NearGas,
+        storage_management::StorageManagement,
+    },
+    serde_json::json,
+};
+
+const FT_TRANSFER_GAS: NearGas = NearGas::from_tgas(10);
+/// Also covers `ft_on_transfer` of the receiver and `ft_resolve_transfer`.
+const FT_TRANSFER_CALL_GAS: NearGas = NearGas::from_tgas(100);
+
+/// A NEP-141 fungible token contract called through an account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FungibleToken {
+    contract: Contract,
+    auto_registration: bool,
+}
+
+impl FungibleToken {
+    pub fn new(account: Account, contract_id: &str) -> Self {
+        Self {
+            contract: Contract::new(account, contract_id),
+            auto_registration: true,
+        }
+    }
+
+    /// Whether transfers first register receivers without a storage deposit, on by default.
+    pub fn with_auto_registration(mut self, auto_registration: bool) -> Self {
+        self.auto_registration = auto_registration;
+        self
+    }
+
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    /// NEP-145 storage management of the token contract.
+    pub fn storage_management(&self) -> StorageManagement {
+        StorageManagement::from_contract(self.contract.clone())
+    }
+
+    pub async fn ft_metadata(&self) -> Result<FungibleTokenMetadata, FungibleTokenError> {
+        Ok(self.contract.view("ft_metadata", &json!({})).await?.value)
+    }
+
+    pub async fn ft_total_supply(&self) -> Result<u128, FungibleTokenError> {
+        let result = self
+            .contract
+            .view::<_, U128>("ft_total_supply", &json!({}))
+            .await?;
+
+        Ok(result.value.into())
+    }
+
+    pub async fn ft_balance_of(&self, account_id: &str) -> Result<u128, FungibleTokenError> {
+        let result = self
+            .contract
+            .view::<_, U128>("ft_balance_of", &json!({ "account_id": account_id }))
+            .await?;
+
+        Ok(result.value.into())
+    }
+
+    /// Registers the receiver first, see `with_auto_registration`.
+    pub async fn ft_transfer(
+        &mut self,
+        receiver_id: &str,
+        amount: u128,
+        memo: Option<String>,
+    ) -> Result<ContractResult<()>, FungibleTokenError> {
+        self.prepare_receiver(receiver_id).await?;
+
+        let args = json!({
+            "receiver_id": receiver_id,
+            "amount": U128(amount),
+            "memo": memo,
+        });
+
+        Ok(self
+            .contract
+            .call("ft_transfer", &args, FT_TRANSFER_GAS, ONE_YOCTO)
+            .await?)
+    }
+
+    /// Returns the amount the receiver kept, the rest is refunded to the sender.
+    pub async fn ft_transfer_call(
+        &mut self,
+        receiver_id: &str,
+        amount: u128,
+        memo: Option<String>,
+        msg: &str,
+    ) -> Result<ContractResult<u128>, FungibleTokenError> {
+        self.prepare_receiver(receiver_id).await?;
+
+        let args = json!({
+            "receiver_id": receiver_id,
+            "amount": U128(amount),
+            "memo": memo,
+            "msg": msg,
+        });
+        let result = self
+            .contract
+            .call::<_, U128>("ft_transfer_call", &args, FT_TRANSFER_CALL_GAS, ONE_YOCTO)
+            .await?;
+
+        Ok(ContractResult {
+            value: result.value.into(),
+            logs: result.logs,
+        })
+    }
+
+    /// Registers unregistered receivers when auto registration is on, otherwise fails with
+    /// `NotRegistered` before sending.
+    async fn prepare_receiver(&self, receiver_id: &str) -> Result<(), FungibleTokenError> {
+        let mut storage_management = self.storage_management();
+
+        if self.auto_registration {
+            storage_management.ensure_registered(receiver_id).await?;
+            return Ok(());
+        }
+
+        match storage_management.is_registered(receiver_id).await? {
+            true => Ok(()),
+            false => Err(FungibleTokenError::NotRegistered {
+                contract_id: self.contract.contract_id().to_string(),
+                account_id: receiver_id.to_string(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::primitives::types::format_decimal;
+
+pub const FT_METADATA_SPEC: &str = "ft-1.0.0";
+
+/// Token metadata returned by `ft_metadata`, per NEP-148.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FungibleTokenMetadata {
+    pub spec: String,
+    pub name: String,
+    pub symbol: String,
+    pub icon: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<String>,
+    pub decimals: u8,
+}
+
+impl FungibleTokenMetadata {
+    /// Formats a raw `amount` rounded to `shown` places, e.g. `1.5 USDC`.
+    pub fn format_amount(&self, amount: u128, shown: usize) -> String {
+        format!(
+            "{} {}",
+            format_decimal(amount, self.decimals as usize, shown),
+            self.symbol
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_format_amount() {
+        let metadata: FungibleTokenMetadata = serde_json::from_str(
+            r#"{
+                "spec": "ft-1.0.0",
+                "name": "USD Coin",
+                "symbol": "USDC",
+                "icon": null,
+                "reference": null,
+                "reference_hash": null,
+                "decimals": 6
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(metadata.format_amount(1_500_000, 6), "1.5 USDC");
+        assert_eq!(metadata.format_amount(1_234_567, 2), "1.23 USDC");
+        assert_eq!(metadata.format_amount(0, 2), "0 USDC");
+    }
+}
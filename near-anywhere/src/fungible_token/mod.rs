@@ -0,0 +1,9 @@
+mod errors;
+mod fungible_token;
+mod fungible_token_metadata;
+
+pub use {
+    errors::FungibleTokenError,
+    fungible_token::FungibleToken,
+    fungible_token_metadata::{FungibleTokenMetadata, FT_METADATA_SPEC},
+};
@@ -1,9 +1,17 @@
 use {
-    crate::crypto::{KeyType, ParseKeyError, PublicKey, SecretKey, Signature},
-    core::{fmt::Display, str::FromStr},
+    crate::{
+        crypto::{KeyType, ParseKeyError, PublicKey, SecretKey, Signature},
+        seed_phrase::{SeedPhrase, SeedPhraseError},
+    },
+    core::{
+        fmt::{Debug, Display, Formatter},
+        str::FromStr,
+    },
+    zeroize::Zeroizing,
 };
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// A secret key with its public key, the secret key is never printed.
+#[derive(Clone)]
 pub struct KeyPair {
     secret_key: SecretKey,
     public_key: PublicKey,
@@ -42,6 +50,22 @@ impl KeyPair {
             public_key,
         })
     }
+
+    /// Key pair of a NEAR wallet seed phrase at the default derivation path.
+    pub fn from_seed_phrase(phrase: &str) -> Result<Self, SeedPhraseError> {
+        Ok(SeedPhrase::parse(phrase)?.key_pair())
+    }
+}
+
+impl From<SecretKey> for KeyPair {
+    fn from(secret_key: SecretKey) -> Self {
+        let public_key = secret_key.public_key();
+
+        Self {
+            secret_key,
+            public_key,
+        }
+    }
 }
 
 impl KeyPair {
@@ -49,18 +73,41 @@ impl KeyPair {
         self.secret_key.sign(message)
     }
 
+    /// Signs a 32 bytes digest such as a transaction hash, see `SecretKey::sign_prehashed`.
+    pub fn sign_prehashed(&self, digest: &[u8; 32]) -> Signature {
+        self.secret_key.sign_prehashed(digest)
+    }
+
     pub fn public_key(&self) -> PublicKey {
         self.public_key.clone()
     }
 
+    pub fn secret_key(&self) -> &SecretKey {
+        &self.secret_key
+    }
+
+    /// Exports the secret key, the only way to get it back as a string.
+    pub fn to_secret_string(&self) -> Zeroizing<String> {
+        self.secret_key.to_secret_string()
+    }
+
     pub fn verify(&self, message: &[u8], signature: Signature) -> bool {
         signature.verify(message, &self.public_key)
     }
 }
 
 impl Display for KeyPair {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", self.secret_key.to_string())
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.public_key)
+    }
+}
+
+impl Debug for KeyPair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("KeyPair")
+            .field("public_key", &self.public_key)
+            .field("secret_key", &self.secret_key)
+            .finish()
     }
 }
 
@@ -81,4 +128,17 @@ mod tests {
         let signature = key_pair.sign(&data);
         assert!(signature.verify(&data, &public_key));
     }
+
+    #[test]
+    pub fn test_secret_key_is_not_leaked() {
+        let secret_key = "ed25519:2LRHYvi3uHHsADkF8HFRyZXJX3BG7HuQpuHw1cWcYSrivxNo5y76vkPA4ezKixS3jQ7e2zCfi4zfXbNAP72j2Ntk";
+        let key_pair = KeyPair::from_secret_key(secret_key).expect("Failed to create key pair");
+
+        assert!(!format!("{:?}", key_pair).contains(&secret_key[8..]));
+        assert_eq!(
+            key_pair.to_string(),
+            "ed25519:G9upgDmY9DPYvkxZrR52Foh7g351TKee1E4BJdCLfZaU"
+        );
+        assert_eq!(key_pair.to_secret_string().as_str(), secret_key);
+    }
 }
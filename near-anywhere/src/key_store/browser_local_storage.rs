@@ -1,7 +1,13 @@
 use {
-    super::{storage_key_for_secret_key, LOCAL_STORAGE_KEY_PREFIX},
-    crate::key_pair::KeyPair,
+    super::{
+        parse_storage_key,
+        storage_key_for_public_key,
+        storage_key_for_secret_key,
+        LOCAL_STORAGE_KEY_PREFIX,
+    },
+    crate::{crypto::PublicKey, key_pair::KeyPair},
     web_sys::Storage,
+    zeroize::Zeroizing,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -19,26 +25,84 @@ impl BrowserLocalStorageKeyStore {
 
 impl BrowserLocalStorageKeyStore {
     pub fn set_key(&self, network_id: &str, account_id: &str, keypair: KeyPair) {
-        let key = storage_key_for_secret_key(network_id, account_id);
+        self.set_record(network_id, account_id, keypair.to_secret_string().as_str());
+    }
+
+    /// Adds a key next to the default one, so an account can hold several keys.
+    pub fn add_key(&self, network_id: &str, account_id: &str, keypair: KeyPair) {
+        let key = storage_key_for_public_key(network_id, account_id, &keypair.public_key());
 
         self.storage
-            .set_item(&key, keypair.to_string().as_str())
+            .set_item(&key, keypair.to_secret_string().as_str())
             .expect("failed to set key pair");
     }
 
+    /// Default key of the account, or its first additional key when there is no default one.
     pub fn get_key(&self, network_id: &str, account_id: &str) -> Option<KeyPair> {
-        let key = storage_key_for_secret_key(network_id, account_id);
-        let value = self.storage.get_item(&key).unwrap();
+        self.get_keys(network_id, account_id).into_iter().next()
+    }
 
-        match value {
-            Some(value) => Some(KeyPair::from_secret_key(&value).unwrap()),
-            None => None,
+    /// Default key first, followed by the additional keys.
+    pub fn get_keys(&self, network_id: &str, account_id: &str) -> Vec<KeyPair> {
+        let mut keys = vec![storage_key_for_secret_key(network_id, account_id)];
+        keys.extend(self.additional_storage_keys(network_id, account_id));
+        let mut result: Vec<KeyPair> = vec![];
+
+        for key in keys {
+            if let Some(value) = self.storage.get_item(&key).unwrap().map(Zeroizing::new) {
+                let keypair = KeyPair::from_secret_key(&value).unwrap();
+                if !result
+                    .iter()
+                    .any(|item| item.public_key() == keypair.public_key())
+                {
+                    result.push(keypair);
+                }
+            }
         }
+
+        result
+    }
+
+    pub fn set_record(&self, network_id: &str, account_id: &str, record: &str) {
+        let key = storage_key_for_secret_key(network_id, account_id);
+
+        self.storage
+            .set_item(&key, record)
+            .expect("failed to set key pair");
+    }
+
+    pub fn get_record(&self, network_id: &str, account_id: &str) -> Option<Zeroizing<String>> {
+        let key = storage_key_for_secret_key(network_id, account_id);
+
+        self.storage.get_item(&key).unwrap().map(Zeroizing::new)
     }
 
+    /// Removes the default key and every additional key of the account.
     pub fn remove_key(&self, network_id: &str, account_id: &str) {
         let key = storage_key_for_secret_key(network_id, account_id);
         self.storage.remove_item(&key).unwrap();
+
+        for key in self.additional_storage_keys(network_id, account_id) {
+            self.storage.remove_item(&key).unwrap();
+        }
+    }
+
+    /// Removes a single key, whether it is the default key or an additional one.
+    pub fn remove_key_by_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) {
+        if let Some(record) = self.get_record(network_id, account_id) {
+            if &KeyPair::from_secret_key(&record).unwrap().public_key() == public_key {
+                let key = storage_key_for_secret_key(network_id, account_id);
+                self.storage.remove_item(&key).unwrap();
+            }
+        }
+
+        let key = storage_key_for_public_key(network_id, account_id, public_key);
+        self.storage.remove_item(&key).unwrap();
     }
 
     pub fn get_networks(&self) -> Vec<String> {
@@ -46,9 +110,10 @@ impl BrowserLocalStorageKeyStore {
         let keys = self.storage_keys();
 
         for key in keys {
-            let parts = key.split(":").collect::<Vec<&str>>();
-            if parts.len() == 4 {
-                result.push(parts[3].to_string());
+            if let Some((_, network_id, _)) = parse_storage_key(&key) {
+                if !result.iter().any(|item| item == network_id) {
+                    result.push(network_id.to_string());
+                }
             }
         }
 
@@ -60,9 +125,14 @@ impl BrowserLocalStorageKeyStore {
         let keys = self.storage_keys();
 
         for key in keys {
-            let parts = key.split(":").collect::<Vec<&str>>();
-            if parts.len() == 4 && parts[3] == network_id {
-                result.push(parts[1].to_string());
+            match parse_storage_key(&key) {
+                Some((account_id, key_network_id, _))
+                    if key_network_id == network_id
+                        && !result.iter().any(|item| item == account_id) =>
+                {
+                    result.push(account_id.to_string());
+                }
+                _ => {}
             }
         }
 
@@ -95,4 +165,22 @@ impl BrowserLocalStorageKeyStore {
 
         keys
     }
+
+    /// Storage keys of the additional keys of the account, sorted.
+    fn additional_storage_keys(&self, network_id: &str, account_id: &str) -> Vec<String> {
+        let mut result = self
+            .storage_keys()
+            .into_iter()
+            .filter(|key| {
+                matches!(
+                    parse_storage_key(key),
+                    Some((key_account_id, key_network_id, Some(_)))
+                        if key_account_id == account_id && key_network_id == network_id
+                )
+            })
+            .collect::<Vec<String>>();
+        result.sort();
+
+        result
+    }
 }
@@ -0,0 +1,1061 @@
+use {
+    super::{KeyStore, KeyStoreError},
+    crate::{
+        crypto::PublicKey,
+        key_pair::KeyPair,
+        serialize::{from_base64, to_base64},
+    },
+    aes_gcm::{
+        aead::{Aead, KeyInit, Payload},
+        Aes256Gcm,
+        Nonce,
+    },
+    core::{
+        fmt::{Debug, Formatter},
+        str::FromStr,
+    },
+    std::sync::{Arc, RwLock},
+    zeroize::Zeroizing,
+};
+
+/// Version of the meta and key records, bumped on any change of the format.
+pub const ENCRYPTED_KEY_STORE_VERSION: u32 = 1;
+
+/// Slot of the meta record in the wrapped key store.
+const META_NETWORK_ID: &str = ".encrypted-key-store";
+const META_ACCOUNT_ID: &str = "meta";
+/// Slot of the meta record of a re-key in progress, next to the meta record.
+const PENDING_META_ACCOUNT_ID: &str = "pending-meta";
+/// Known plaintext encrypted in the meta record, a failed decryption means a wrong passphrase.
+const CHECK_PLAINTEXT: &[u8] = b"near-anywhere:encrypted-key-store";
+const KEY_LENGTH: usize = 32;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+/// Cost of the scrypt key derivation, `2^log_n` iterations.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScryptParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        Self {
+            log_n: 15,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+/// Stored once per key store, holds what is needed to derive and check the key.
+#[derive(Serialize, Deserialize)]
+struct MetaRecord {
+    version: u32,
+    kdf: ScryptParams,
+    salt: String,
+    nonce: String,
+    check: String,
+}
+
+/// Written before a re-key rewrites any account record. Each key is wrapped with the other, so
+/// either passphrase can finish an interrupted re-key.
+#[derive(Serialize, Deserialize)]
+struct PendingMetaRecord {
+    #[serde(flatten)]
+    meta: MetaRecord,
+    /// Key of the current meta record, encrypted with the new key.
+    previous_key: WrappedKey,
+    /// New key, encrypted with the key of the current meta record.
+    next_key: WrappedKey,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WrappedKey {
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Stored in place of the secret keys of an account, the default key first.
+#[derive(Serialize, Deserialize)]
+struct EncryptedRecord {
+    version: u32,
+    #[serde(flatten)]
+    default_key: EncryptedKey,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    additional_keys: Vec<EncryptedKey>,
+}
+
+impl EncryptedRecord {
+    fn keys(&self) -> impl Iterator<Item = &EncryptedKey> {
+        std::iter::once(&self.default_key).chain(self.additional_keys.iter())
+    }
+}
+
+/// A secret key, the public key is kept in clear for listing.
+#[derive(Serialize, Deserialize, Clone)]
+struct EncryptedKey {
+    public_key: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Encrypts the secret keys of any other key store with a key derived from a passphrase.
+///
+/// The key is derived with scrypt and records are encrypted with AES-256-GCM, bound to their
+/// network and account. Clones share the lock state, so a clone can be wrapped in
+/// `KeyStore::Encrypted` and unlocked later.
+#[derive(Clone)]
+pub struct EncryptedKeyStore {
+    inner: Box<KeyStore>,
+    kdf_params: ScryptParams,
+    key: Arc<RwLock<Option<Zeroizing<[u8; KEY_LENGTH]>>>>,
+    /// Writes left before `write_record` fails, to test interrupted re-keys.
+    #[cfg(test)]
+    writes_before_failure: Arc<RwLock<Option<usize>>>,
+}
+
+impl EncryptedKeyStore {
+    pub fn new(inner: KeyStore) -> Self {
+        Self {
+            inner: Box::new(inner),
+            kdf_params: ScryptParams::default(),
+            key: Arc::new(RwLock::new(None)),
+            #[cfg(test)]
+            writes_before_failure: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Params used when the key store is initialized, existing stores keep their own.
+    pub fn with_kdf_params(mut self, kdf_params: ScryptParams) -> Self {
+        self.kdf_params = kdf_params;
+        self
+    }
+
+    pub fn inner(&self) -> &KeyStore {
+        &self.inner
+    }
+
+    pub async fn is_initialized(&self) -> Result<bool, KeyStoreError> {
+        Ok(self.read_meta().await?.is_some())
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.key.read().expect("key lock is poisoned").is_none()
+    }
+
+    /// Derives the key from the passphrase, the first unlock initializes the key store.
+    ///
+    /// An interrupted re-key is finished first, either passphrase unlocks it.
+    pub async fn unlock(&self, passphrase: &str) -> Result<(), KeyStoreError> {
+        let key = match self.read_meta().await? {
+            Some(meta) => self.open(&meta, passphrase).await?,
+            None => {
+                let (meta, key) = seal_meta(passphrase, self.kdf_params)?;
+                self.write_meta(&meta).await?;
+                key
+            }
+        };
+        *self.key.write().expect("key lock is poisoned") = Some(key);
+
+        Ok(())
+    }
+
+    pub fn lock(&self) {
+        *self.key.write().expect("key lock is poisoned") = None;
+    }
+
+    /// Re-encrypts every key with a key derived from the new passphrase.
+    ///
+    /// The new meta record is stored as pending before any account record is rewritten and
+    /// replaces the current one at the end. An interrupted re-key is finished by the next
+    /// unlock or re-key with either passphrase.
+    pub async fn rekey(&self, passphrase: &str, new_passphrase: &str) -> Result<(), KeyStoreError> {
+        let meta = self
+            .read_meta()
+            .await?
+            .ok_or(KeyStoreError::NotInitialized)?;
+        let key = self.open(&meta, passphrase).await?;
+        let accounts = self.decrypt_accounts(&[&key]).await?;
+        let (meta, new_key) = seal_meta(new_passphrase, self.kdf_params)?;
+        let pending = PendingMetaRecord {
+            meta,
+            previous_key: wrap_key(&new_key, &key)?,
+            next_key: wrap_key(&key, &new_key)?,
+        };
+
+        self.write_pending_meta(&pending).await?;
+        // Records are now under either key, the next unlock finishes the re-key.
+        if let Err(error) = self.encrypt_accounts(&new_key, accounts).await {
+            self.lock();
+            return Err(error);
+        }
+        self.commit_meta(&pending.meta).await?;
+        *self.key.write().expect("key lock is poisoned") = Some(new_key);
+
+        Ok(())
+    }
+
+    /// Sets the default key, the additional keys of the account are kept.
+    pub async fn set_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        keypair: KeyPair,
+    ) -> Result<(), KeyStoreError> {
+        let key = self.unlocked_key().await?;
+        let mut keys = self.decrypt_keys(&key, network_id, account_id).await?;
+        keys.retain(|item| item.public_key() != keypair.public_key());
+        keys.insert(0, keypair);
+
+        self.encrypt_keys(&key, network_id, account_id, &keys).await
+    }
+
+    /// Adds a key next to the default one, it becomes the default key of an account without keys.
+    pub async fn add_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        keypair: KeyPair,
+    ) -> Result<(), KeyStoreError> {
+        let key = self.unlocked_key().await?;
+        let mut keys = self.decrypt_keys(&key, network_id, account_id).await?;
+
+        match keys
+            .iter()
+            .position(|item| item.public_key() == keypair.public_key())
+        {
+            Some(index) => keys[index] = keypair,
+            None => keys.push(keypair),
+        }
+
+        self.encrypt_keys(&key, network_id, account_id, &keys).await
+    }
+
+    pub async fn get_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<KeyPair>, KeyStoreError> {
+        Ok(self
+            .get_keys(network_id, account_id)
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    /// Default key first, followed by the additional keys.
+    pub async fn get_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<KeyPair>, KeyStoreError> {
+        let key = self.unlocked_key().await?;
+
+        self.decrypt_keys(&key, network_id, account_id).await
+    }
+
+    /// Public keys are stored in clear, they can be listed while the key store is locked.
+    pub async fn get_public_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<PublicKey>, KeyStoreError> {
+        match self.read_record(network_id, account_id).await? {
+            Some(record) => record
+                .keys()
+                .map(|item| PublicKey::from_str(&item.public_key).map_err(invalid_record))
+                .collect(),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Makes a stored key the default one, the previous default key is kept as an additional key.
+    pub async fn set_default_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<(), KeyStoreError> {
+        let key = self.unlocked_key().await?;
+        let mut keys = self.decrypt_keys(&key, network_id, account_id).await?;
+        let index = keys
+            .iter()
+            .position(|item| &item.public_key() == public_key)
+            .ok_or_else(|| KeyStoreError::KeyNotFound {
+                account_id: account_id.to_string(),
+                public_key: public_key.to_string(),
+            })?;
+        let keypair = keys.remove(index);
+        keys.insert(0, keypair);
+
+        self.encrypt_keys(&key, network_id, account_id, &keys).await
+    }
+
+    pub async fn remove_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<(), KeyStoreError> {
+        self.inner.remove_key(network_id, account_id).await
+    }
+
+    /// Removes a single key, the first additional key takes the place of a removed default key.
+    pub async fn remove_key_by_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<(), KeyStoreError> {
+        let record = match self.read_record(network_id, account_id).await? {
+            Some(record) => record,
+            None => return Ok(()),
+        };
+        let public_key = public_key.to_string();
+        let mut keys = record
+            .keys()
+            .filter(|item| item.public_key != public_key)
+            .cloned()
+            .collect::<Vec<EncryptedKey>>();
+
+        if keys.is_empty() {
+            return self.inner.remove_key(network_id, account_id).await;
+        }
+        let default_key = keys.remove(0);
+        let record = EncryptedRecord {
+            version: ENCRYPTED_KEY_STORE_VERSION,
+            default_key,
+            additional_keys: keys,
+        };
+
+        self.write_record(network_id, account_id, &record).await
+    }
+
+    pub async fn get_networks(&self) -> Result<Vec<String>, KeyStoreError> {
+        Ok(self
+            .inner
+            .get_networks()
+            .await?
+            .into_iter()
+            .filter(|network_id| network_id != META_NETWORK_ID)
+            .collect())
+    }
+
+    pub async fn get_accounts(&self, network_id: &str) -> Result<Vec<String>, KeyStoreError> {
+        self.inner.get_accounts(network_id).await
+    }
+
+    /// Removes every key and the meta record, the next unlock starts a new key store.
+    pub async fn clear(&self) -> Result<(), KeyStoreError> {
+        self.inner.clear().await?;
+        self.lock();
+
+        Ok(())
+    }
+
+    async fn unlocked_key(&self) -> Result<Zeroizing<[u8; KEY_LENGTH]>, KeyStoreError> {
+        let key = self.key.read().expect("key lock is poisoned").clone();
+
+        match key {
+            Some(key) => Ok(key),
+            None if self.is_initialized().await? => Err(KeyStoreError::Locked),
+            None => Err(KeyStoreError::NotInitialized),
+        }
+    }
+
+    /// Derives the key of `meta`, or of the pending meta record after finishing its re-key.
+    async fn open(
+        &self,
+        meta: &MetaRecord,
+        passphrase: &str,
+    ) -> Result<Zeroizing<[u8; KEY_LENGTH]>, KeyStoreError> {
+        let pending = match self.read_pending_meta().await? {
+            Some(pending) => pending,
+            None => return open_meta(meta, passphrase),
+        };
+
+        // Interrupted after the new meta record was written, only the cleanup is left.
+        if pending.meta.salt == meta.salt {
+            self.inner
+                .remove_key(META_NETWORK_ID, PENDING_META_ACCOUNT_ID)
+                .await?;
+            return open_meta(meta, passphrase);
+        }
+
+        let (key, new_key) = match open_meta(meta, passphrase) {
+            Ok(key) => {
+                let new_key = unwrap_key(&key, &pending.next_key)?;
+                (key, new_key)
+            }
+            Err(KeyStoreError::InvalidPassphrase) => {
+                let new_key = open_meta(&pending.meta, passphrase)?;
+                let key = unwrap_key(&new_key, &pending.previous_key)?;
+                (key, new_key)
+            }
+            Err(error) => return Err(error),
+        };
+        let accounts = self.decrypt_accounts(&[&new_key, &key]).await?;
+
+        self.encrypt_accounts(&new_key, accounts).await?;
+        self.commit_meta(&pending.meta).await?;
+
+        Ok(new_key)
+    }
+
+    /// Keys of every account, each record is decrypted with the first key that opens it.
+    async fn decrypt_accounts(
+        &self,
+        keys: &[&[u8; KEY_LENGTH]],
+    ) -> Result<Vec<(String, String, Vec<KeyPair>)>, KeyStoreError> {
+        let mut accounts = vec![];
+
+        for network_id in self.get_networks().await? {
+            for account_id in self.inner.get_accounts(&network_id).await? {
+                let record = match self.read_record(&network_id, &account_id).await? {
+                    Some(record) => record,
+                    None => continue,
+                };
+                let keypairs = record
+                    .keys()
+                    .map(|item| decrypt_key_with_any(keys, &network_id, &account_id, item))
+                    .collect::<Result<Vec<KeyPair>, KeyStoreError>>()?;
+                accounts.push((network_id.clone(), account_id, keypairs));
+            }
+        }
+
+        Ok(accounts)
+    }
+
+    async fn encrypt_accounts(
+        &self,
+        key: &[u8; KEY_LENGTH],
+        accounts: Vec<(String, String, Vec<KeyPair>)>,
+    ) -> Result<(), KeyStoreError> {
+        for (network_id, account_id, keys) in accounts {
+            self.encrypt_keys(key, &network_id, &account_id, &keys)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the meta record, then drops the pending one.
+    async fn commit_meta(&self, meta: &MetaRecord) -> Result<(), KeyStoreError> {
+        self.write_meta(meta).await?;
+
+        self.inner
+            .remove_key(META_NETWORK_ID, PENDING_META_ACCOUNT_ID)
+            .await
+    }
+
+    async fn read_pending_meta(&self) -> Result<Option<PendingMetaRecord>, KeyStoreError> {
+        match self
+            .inner
+            .get_record(META_NETWORK_ID, PENDING_META_ACCOUNT_ID)
+            .await?
+        {
+            Some(record) => {
+                let pending: PendingMetaRecord = parse_record(&record)?;
+                check_version(pending.meta.version)?;
+                Ok(Some(pending))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn write_pending_meta(&self, pending: &PendingMetaRecord) -> Result<(), KeyStoreError> {
+        let record = serde_json::to_string(pending).map_err(invalid_record)?;
+
+        self.inner
+            .set_record(META_NETWORK_ID, PENDING_META_ACCOUNT_ID, &record)
+            .await
+    }
+
+    async fn read_meta(&self) -> Result<Option<MetaRecord>, KeyStoreError> {
+        match self
+            .inner
+            .get_record(META_NETWORK_ID, META_ACCOUNT_ID)
+            .await?
+        {
+            Some(record) => {
+                let meta: MetaRecord = parse_record(&record)?;
+                check_version(meta.version)?;
+                Ok(Some(meta))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn write_meta(&self, meta: &MetaRecord) -> Result<(), KeyStoreError> {
+        let record = serde_json::to_string(meta).map_err(invalid_record)?;
+
+        self.inner
+            .set_record(META_NETWORK_ID, META_ACCOUNT_ID, &record)
+            .await
+    }
+
+    async fn read_record(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<EncryptedRecord>, KeyStoreError> {
+        match self.inner.get_record(network_id, account_id).await? {
+            Some(record) => {
+                let record: EncryptedRecord = parse_record(&record)?;
+                check_version(record.version)?;
+                Ok(Some(record))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn write_record(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        record: &EncryptedRecord,
+    ) -> Result<(), KeyStoreError> {
+        #[cfg(test)]
+        if let Some(writes) = self
+            .writes_before_failure
+            .write()
+            .expect("write lock is poisoned")
+            .as_mut()
+        {
+            match *writes {
+                0 => {
+                    return Err(KeyStoreError::Io {
+                        error_message: "write failed".to_string(),
+                    })
+                }
+                _ => *writes -= 1,
+            }
+        }
+        let record = serde_json::to_string(record).map_err(invalid_record)?;
+
+        self.inner.set_record(network_id, account_id, &record).await
+    }
+
+    /// Replaces the keys of the account, the first key becomes the default one.
+    async fn encrypt_keys(
+        &self,
+        key: &[u8; KEY_LENGTH],
+        network_id: &str,
+        account_id: &str,
+        keys: &[KeyPair],
+    ) -> Result<(), KeyStoreError> {
+        let record = encrypt_record(key, network_id, account_id, keys)?;
+
+        self.write_record(network_id, account_id, &record).await
+    }
+
+    async fn decrypt_keys(
+        &self,
+        key: &[u8; KEY_LENGTH],
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<KeyPair>, KeyStoreError> {
+        match self.read_record(network_id, account_id).await? {
+            Some(record) => record
+                .keys()
+                .map(|item| decrypt_key(key, network_id, account_id, item))
+                .collect(),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+impl PartialEq for EncryptedKeyStore {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+            && self.kdf_params == other.kdf_params
+            && Arc::ptr_eq(&self.key, &other.key)
+    }
+}
+
+impl Debug for EncryptedKeyStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("EncryptedKeyStore")
+            .field("inner", &self.inner)
+            .field("kdf_params", &self.kdf_params)
+            .field("locked", &self.is_locked())
+            .finish()
+    }
+}
+
+fn invalid_record<E: ToString>(error: E) -> KeyStoreError {
+    KeyStoreError::InvalidRecord {
+        error_message: error.to_string(),
+    }
+}
+
+fn parse_record<T: serde::de::DeserializeOwned>(record: &str) -> Result<T, KeyStoreError> {
+    serde_json::from_str(record).map_err(invalid_record)
+}
+
+fn check_version(version: u32) -> Result<(), KeyStoreError> {
+    match version == ENCRYPTED_KEY_STORE_VERSION {
+        true => Ok(()),
+        false => Err(KeyStoreError::UnsupportedVersion { version }),
+    }
+}
+
+fn record_aad(network_id: &str, account_id: &str) -> Vec<u8> {
+    format!("{}:{}", network_id, account_id).into_bytes()
+}
+
+fn random_bytes<const N: usize>() -> Result<[u8; N], KeyStoreError> {
+    let mut bytes = [0u8; N];
+    getrandom::getrandom(&mut bytes).map_err(|err| KeyStoreError::Encryption {
+        error_message: err.to_string(),
+    })?;
+
+    Ok(bytes)
+}
+
+fn derive_key(
+    passphrase: &str,
+    salt: &[u8],
+    params: ScryptParams,
+) -> Result<Zeroizing<[u8; KEY_LENGTH]>, KeyStoreError> {
+    let encryption_error = |error_message: String| KeyStoreError::Encryption { error_message };
+    let scrypt_params = scrypt::Params::new(params.log_n, params.r, params.p)
+        .map_err(|err| encryption_error(err.to_string()))?;
+    let mut key = Zeroizing::new([0u8; KEY_LENGTH]);
+    scrypt::scrypt(passphrase.as_bytes(), salt, &scrypt_params, key.as_mut())
+        .map_err(|err| encryption_error(err.to_string()))?;
+
+    Ok(key)
+}
+
+/// Returns the base64 encoded nonce and ciphertext.
+fn encrypt(
+    key: &[u8; KEY_LENGTH],
+    plaintext: &[u8],
+    aad: &[u8],
+) -> Result<(String, String), KeyStoreError> {
+    let cipher = Aes256Gcm::new_from_slice(key).expect("key length is valid");
+    let nonce = random_bytes::<NONCE_LENGTH>()?;
+    let ciphertext = cipher
+        .encrypt(
+            &Nonce::from(nonce),
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
+        .map_err(|_| KeyStoreError::Encryption {
+            error_message: "fail to encrypt".to_string(),
+        })?;
+
+    Ok((to_base64(nonce), to_base64(ciphertext)))
+}
+
+/// `None` when the key is wrong or the ciphertext was tampered with.
+fn decrypt(
+    key: &[u8; KEY_LENGTH],
+    nonce: &str,
+    ciphertext: &str,
+    aad: &[u8],
+) -> Result<Option<Zeroizing<Vec<u8>>>, KeyStoreError> {
+    let nonce: [u8; NONCE_LENGTH] = from_base64(nonce)
+        .map_err(invalid_record)?
+        .try_into()
+        .map_err(|_| invalid_record("invalid nonce length"))?;
+    let ciphertext = from_base64(ciphertext).map_err(invalid_record)?;
+    let cipher = Aes256Gcm::new_from_slice(key).expect("key length is valid");
+    let plaintext = cipher.decrypt(
+        &Nonce::from(nonce),
+        Payload {
+            msg: &ciphertext,
+            aad,
+        },
+    );
+
+    Ok(plaintext.ok().map(Zeroizing::new))
+}
+
+/// Keys of an account are encrypted with the same associated data, bound to the account.
+fn encrypt_record(
+    key: &[u8; KEY_LENGTH],
+    network_id: &str,
+    account_id: &str,
+    keys: &[KeyPair],
+) -> Result<EncryptedRecord, KeyStoreError> {
+    let mut keys = keys
+        .iter()
+        .map(|keypair| encrypt_key(key, network_id, account_id, keypair))
+        .collect::<Result<Vec<EncryptedKey>, KeyStoreError>>()?;
+
+    if keys.is_empty() {
+        return Err(invalid_record("an account record needs at least one key"));
+    }
+    let default_key = keys.remove(0);
+
+    Ok(EncryptedRecord {
+        version: ENCRYPTED_KEY_STORE_VERSION,
+        default_key,
+        additional_keys: keys,
+    })
+}
+
+fn encrypt_key(
+    key: &[u8; KEY_LENGTH],
+    network_id: &str,
+    account_id: &str,
+    keypair: &KeyPair,
+) -> Result<EncryptedKey, KeyStoreError> {
+    let (nonce, ciphertext) = encrypt(
+        key,
+        keypair.to_secret_string().as_bytes(),
+        &record_aad(network_id, account_id),
+    )?;
+
+    Ok(EncryptedKey {
+        public_key: keypair.public_key().to_string(),
+        nonce,
+        ciphertext,
+    })
+}
+
+fn decrypt_key(
+    key: &[u8; KEY_LENGTH],
+    network_id: &str,
+    account_id: &str,
+    encrypted_key: &EncryptedKey,
+) -> Result<KeyPair, KeyStoreError> {
+    let secret_key = decrypt(
+        key,
+        &encrypted_key.nonce,
+        &encrypted_key.ciphertext,
+        &record_aad(network_id, account_id),
+    )?
+    .ok_or_else(|| KeyStoreError::Encryption {
+        error_message: format!("fail to decrypt the key of '{}'", account_id),
+    })?;
+    let secret_key =
+        Zeroizing::new(String::from_utf8(secret_key.to_vec()).map_err(invalid_record)?);
+    let keypair = KeyPair::from_secret_key(&secret_key).map_err(invalid_record)?;
+
+    match keypair.public_key().to_string() == encrypted_key.public_key {
+        true => Ok(keypair),
+        false => Err(KeyStoreError::InvalidRecord {
+            error_message: "public key does not match the secret key".to_string(),
+        }),
+    }
+}
+
+/// Decrypts with the first of `keys` that opens the key.
+fn decrypt_key_with_any(
+    keys: &[&[u8; KEY_LENGTH]],
+    network_id: &str,
+    account_id: &str,
+    encrypted_key: &EncryptedKey,
+) -> Result<KeyPair, KeyStoreError> {
+    let mut result = Err(invalid_record("no key to decrypt with"));
+
+    for key in keys {
+        result = decrypt_key(key, network_id, account_id, encrypted_key);
+        if result.is_ok() {
+            break;
+        }
+    }
+
+    result
+}
+
+/// Encrypts `wrapped` with `key`, to store one key under another.
+fn wrap_key(
+    key: &[u8; KEY_LENGTH],
+    wrapped: &[u8; KEY_LENGTH],
+) -> Result<WrappedKey, KeyStoreError> {
+    let (nonce, ciphertext) = encrypt(key, wrapped, b"wrapped-key")?;
+
+    Ok(WrappedKey { nonce, ciphertext })
+}
+
+fn unwrap_key(
+    key: &[u8; KEY_LENGTH],
+    wrapped: &WrappedKey,
+) -> Result<Zeroizing<[u8; KEY_LENGTH]>, KeyStoreError> {
+    let plaintext = decrypt(key, &wrapped.nonce, &wrapped.ciphertext, b"wrapped-key")?
+        .ok_or_else(|| invalid_record("fail to unwrap the key of the pending re-key"))?;
+    let mut result = Zeroizing::new([0u8; KEY_LENGTH]);
+    match plaintext.len() == KEY_LENGTH {
+        true => result.copy_from_slice(&plaintext),
+        false => return Err(invalid_record("invalid wrapped key length")),
+    }
+
+    Ok(result)
+}
+
+fn seal_meta(
+    passphrase: &str,
+    kdf: ScryptParams,
+) -> Result<(MetaRecord, Zeroizing<[u8; KEY_LENGTH]>), KeyStoreError> {
+    let salt = random_bytes::<SALT_LENGTH>()?;
+    let key = derive_key(passphrase, &salt, kdf)?;
+    let (nonce, check) = encrypt(&key, CHECK_PLAINTEXT, &[])?;
+    let meta = MetaRecord {
+        version: ENCRYPTED_KEY_STORE_VERSION,
+        kdf,
+        salt: to_base64(salt),
+        nonce,
+        check,
+    };
+
+    Ok((meta, key))
+}
+
+fn open_meta(
+    meta: &MetaRecord,
+    passphrase: &str,
+) -> Result<Zeroizing<[u8; KEY_LENGTH]>, KeyStoreError> {
+    let salt = from_base64(&meta.salt).map_err(invalid_record)?;
+    let key = derive_key(passphrase, &salt, meta.kdf)?;
+
+    match decrypt(&key, &meta.nonce, &meta.check, &[])? {
+        Some(plaintext) if plaintext.as_slice() == CHECK_PLAINTEXT => Ok(key),
+        _ => Err(KeyStoreError::InvalidPassphrase),
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use {super::*, crate::crypto::KeyType};
+
+    const NETWORK_ID: &str = "testnet";
+    const ACCOUNT_ID: &str = "alice.testnet";
+
+    fn key_store(dir: &tempfile::TempDir) -> EncryptedKeyStore {
+        EncryptedKeyStore::new(KeyStore::file_system_key_store(dir.path())).with_kdf_params(
+            ScryptParams {
+                log_n: 4,
+                r: 8,
+                p: 1,
+            },
+        )
+    }
+
+    #[tokio::test]
+    pub async fn test_lock_and_unlock() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_store = key_store(&dir);
+        let keypair = KeyPair::from_random(KeyType::ED25519);
+
+        assert!(key_store.is_locked());
+        assert!(matches!(
+            key_store
+                .set_key(NETWORK_ID, ACCOUNT_ID, keypair.clone())
+                .await,
+            Err(KeyStoreError::NotInitialized)
+        ));
+        assert!(matches!(
+            key_store.rekey("passphrase", "new passphrase").await,
+            Err(KeyStoreError::NotInitialized)
+        ));
+
+        key_store.unlock("passphrase").await.unwrap();
+        key_store
+            .set_key(NETWORK_ID, ACCOUNT_ID, keypair.clone())
+            .await
+            .unwrap();
+        let record = key_store
+            .inner()
+            .get_record(NETWORK_ID, ACCOUNT_ID)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!record.contains(&keypair.to_secret_string()[8..]));
+        assert_eq!(key_store.get_networks().await.unwrap(), vec![NETWORK_ID]);
+
+        key_store.lock();
+        assert!(matches!(
+            key_store.get_key(NETWORK_ID, ACCOUNT_ID).await,
+            Err(KeyStoreError::Locked)
+        ));
+        assert!(matches!(
+            key_store.unlock("wrong passphrase").await,
+            Err(KeyStoreError::InvalidPassphrase)
+        ));
+
+        let wrapped = KeyStore::Encrypted(key_store.clone());
+        key_store.unlock("passphrase").await.unwrap();
+        assert_eq!(
+            wrapped
+                .get_key(NETWORK_ID, ACCOUNT_ID)
+                .await
+                .unwrap()
+                .unwrap()
+                .public_key(),
+            keypair.public_key()
+        );
+    }
+
+    #[tokio::test]
+    pub async fn test_rekey() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_store = key_store(&dir);
+        let keypair = KeyPair::from_random(KeyType::ED25519);
+
+        key_store.unlock("old passphrase").await.unwrap();
+        key_store
+            .set_key(NETWORK_ID, ACCOUNT_ID, keypair.clone())
+            .await
+            .unwrap();
+        assert!(matches!(
+            key_store.rekey("wrong passphrase", "new passphrase").await,
+            Err(KeyStoreError::InvalidPassphrase)
+        ));
+        key_store
+            .rekey("old passphrase", "new passphrase")
+            .await
+            .unwrap();
+
+        let reopened = self::key_store(&dir);
+        assert!(reopened.unlock("old passphrase").await.is_err());
+        reopened.unlock("new passphrase").await.unwrap();
+        assert_eq!(
+            reopened
+                .get_key(NETWORK_ID, ACCOUNT_ID)
+                .await
+                .unwrap()
+                .unwrap()
+                .public_key(),
+            keypair.public_key()
+        );
+    }
+
+    /// Key store with three accounts whose re-key failed after rewriting the first record.
+    async fn interrupted_rekey(dir: &tempfile::TempDir) -> Vec<KeyPair> {
+        let key_store = key_store(dir);
+        let keypairs = (0..3)
+            .map(|_| KeyPair::from_random(KeyType::ED25519))
+            .collect::<Vec<KeyPair>>();
+
+        key_store.unlock("old passphrase").await.unwrap();
+        for (index, keypair) in keypairs.iter().enumerate() {
+            key_store
+                .set_key(
+                    NETWORK_ID,
+                    &format!("account{}.testnet", index),
+                    keypair.clone(),
+                )
+                .await
+                .unwrap();
+        }
+        *key_store.writes_before_failure.write().unwrap() = Some(1);
+        assert!(matches!(
+            key_store.rekey("old passphrase", "new passphrase").await,
+            Err(KeyStoreError::Io { .. })
+        ));
+        assert!(key_store.is_locked());
+
+        keypairs
+    }
+
+    #[tokio::test]
+    pub async fn test_interrupted_rekey() {
+        for passphrase in ["old passphrase", "new passphrase"] {
+            let dir = tempfile::tempdir().unwrap();
+            let keypairs = interrupted_rekey(&dir).await;
+
+            let reopened = key_store(&dir);
+            reopened.unlock(passphrase).await.unwrap();
+            for (index, keypair) in keypairs.iter().enumerate() {
+                let account_id = format!("account{}.testnet", index);
+                assert_eq!(
+                    reopened
+                        .get_key(NETWORK_ID, &account_id)
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .public_key(),
+                    keypair.public_key()
+                );
+            }
+
+            // The unlock finished the re-key.
+            let reopened = key_store(&dir);
+            assert!(matches!(
+                reopened.unlock("old passphrase").await,
+                Err(KeyStoreError::InvalidPassphrase)
+            ));
+            reopened.unlock("new passphrase").await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    pub async fn test_record_is_bound_to_its_account() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_store = key_store(&dir);
+
+        key_store.unlock("passphrase").await.unwrap();
+        key_store
+            .set_key(
+                NETWORK_ID,
+                ACCOUNT_ID,
+                KeyPair::from_random(KeyType::ED25519),
+            )
+            .await
+            .unwrap();
+        let record = key_store
+            .inner()
+            .get_record(NETWORK_ID, ACCOUNT_ID)
+            .await
+            .unwrap()
+            .unwrap();
+        key_store
+            .inner()
+            .set_record(NETWORK_ID, "bob.testnet", &record)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            key_store.get_key(NETWORK_ID, "bob.testnet").await,
+            Err(KeyStoreError::Encryption { .. })
+        ));
+    }
+
+    #[tokio::test]
+    pub async fn test_multiple_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_store = key_store(&dir);
+        let default_keypair = KeyPair::from_random(KeyType::ED25519);
+        let other_keypair = KeyPair::from_random(KeyType::ED25519);
+
+        key_store.unlock("passphrase").await.unwrap();
+        key_store
+            .set_key(NETWORK_ID, ACCOUNT_ID, default_keypair.clone())
+            .await
+            .unwrap();
+        key_store
+            .add_key(NETWORK_ID, ACCOUNT_ID, other_keypair.clone())
+            .await
+            .unwrap();
+        key_store
+            .set_default_key(NETWORK_ID, ACCOUNT_ID, &other_keypair.public_key())
+            .await
+            .unwrap();
+
+        key_store.lock();
+        assert_eq!(
+            key_store
+                .get_public_keys(NETWORK_ID, ACCOUNT_ID)
+                .await
+                .unwrap(),
+            vec![other_keypair.public_key(), default_keypair.public_key()]
+        );
+
+        key_store.unlock("passphrase").await.unwrap();
+        key_store
+            .remove_key_by_public_key(NETWORK_ID, ACCOUNT_ID, &other_keypair.public_key())
+            .await
+            .unwrap();
+        let keys = key_store.get_keys(NETWORK_ID, ACCOUNT_ID).await.unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].public_key(), default_keypair.public_key());
+    }
+}
@@ -0,0 +1,42 @@
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum KeyStoreError {
+    #[error("key store io error: {error_message}")]
+    Io { error_message: String },
+    #[error("invalid key file '{path}': {error_message}")]
+    InvalidKeyFile { path: String, error_message: String },
+    #[error("invalid key store name '{name}'")]
+    InvalidName { name: String },
+    #[error("key store is not initialized")]
+    NotInitialized,
+    #[error("key store is locked")]
+    Locked,
+    #[error("invalid passphrase")]
+    InvalidPassphrase,
+    #[error("encryption error: {error_message}")]
+    Encryption { error_message: String },
+    #[error("invalid key store record: {error_message}")]
+    InvalidRecord { error_message: String },
+    #[error("unsupported key store version {version}")]
+    UnsupportedVersion { version: u32 },
+    #[error("browser error: {error_message}")]
+    Browser { error_message: String },
+    #[error("key '{public_key}' of '{account_id}' not found")]
+    KeyNotFound {
+        account_id: String,
+        public_key: String,
+    },
+    #[error("key of '{account_id}' is not extractable")]
+    NonExtractable { account_id: String },
+    #[error("at least one key store is required")]
+    NoKeyStores,
+    #[error("key store index {index} is out of range of {len} key stores")]
+    InvalidKeyStoreIndex { index: usize, len: usize },
+}
+
+impl From<std::io::Error> for KeyStoreError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io {
+            error_message: error.to_string(),
+        }
+    }
+}
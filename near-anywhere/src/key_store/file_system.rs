@@ -0,0 +1,577 @@
+use {
+    super::KeyStoreError,
+    crate::{crypto::PublicKey, key_pair::KeyPair},
+    std::{
+        ffi::OsStr,
+        fs,
+        io::{ErrorKind, Write},
+        path::{Path, PathBuf},
+    },
+    zeroize::{Zeroize, Zeroizing},
+};
+
+/// Directory used by near-cli and near-api-js, relative to the home directory.
+pub const NEAR_CREDENTIALS_DIR: &str = ".near-credentials";
+
+const KEY_FILE_EXTENSION: &str = "json";
+
+/// Key file in the format of near-cli, older files use `secret_key` instead of `private_key`.
+#[derive(Serialize, Deserialize)]
+struct KeyFile {
+    account_id: String,
+    public_key: String,
+    #[serde(alias = "secret_key")]
+    private_key: String,
+}
+
+impl Drop for KeyFile {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+/// Stores keys as near-cli does, the default key of an account lives in
+/// `<network>/<account>.json` and additional keys in `<network>/<account>/<public_key>.json`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileSystemKeyStore {
+    root: PathBuf,
+}
+
+impl FileSystemKeyStore {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Key store over `~/.near-credentials`.
+    pub fn near_credentials() -> Result<Self, KeyStoreError> {
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .ok_or_else(|| KeyStoreError::Io {
+                error_message: "home directory is not set".to_string(),
+            })?;
+
+        Ok(Self::new(Path::new(&home).join(NEAR_CREDENTIALS_DIR)))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn set_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        keypair: KeyPair,
+    ) -> Result<(), KeyStoreError> {
+        let path = self.account_path(network_id, account_id)?;
+
+        write_key_file(&default_key_path(&path), account_id, &keypair)
+    }
+
+    /// Writes `record` as the content of `<account>.json`, used by wrapping key stores.
+    pub fn set_record(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        record: &str,
+    ) -> Result<(), KeyStoreError> {
+        let path = self.account_path(network_id, account_id)?;
+
+        write_private_file(&default_key_path(&path), record)
+    }
+
+    pub fn get_record(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<Zeroizing<String>>, KeyStoreError> {
+        let path = self.account_path(network_id, account_id)?;
+
+        read_private_file(&default_key_path(&path))
+    }
+
+    /// Adds a key next to the default one, so an account can hold several keys.
+    pub fn add_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        keypair: KeyPair,
+    ) -> Result<(), KeyStoreError> {
+        let path = self
+            .account_path(network_id, account_id)?
+            .join(public_key_file_name(&keypair.public_key()));
+
+        write_key_file(&path, account_id, &keypair)
+    }
+
+    /// Default key of the account, or its first additional key when there is no default one.
+    pub fn get_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<KeyPair>, KeyStoreError> {
+        let path = self.account_path(network_id, account_id)?;
+
+        match read_key_file(&default_key_path(&path))? {
+            Some(keypair) => Ok(Some(keypair)),
+            None => Ok(self.get_additional_keys(&path)?.into_iter().next()),
+        }
+    }
+
+    /// Default key first, followed by the additional keys.
+    pub fn get_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<KeyPair>, KeyStoreError> {
+        let path = self.account_path(network_id, account_id)?;
+        let mut result = vec![];
+
+        if let Some(keypair) = read_key_file(&default_key_path(&path))? {
+            result.push(keypair);
+        }
+        for keypair in self.get_additional_keys(&path)? {
+            if !result
+                .iter()
+                .any(|item| item.public_key() == keypair.public_key())
+            {
+                result.push(keypair);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Removes the default key and every additional key of the account.
+    pub fn remove_key(&self, network_id: &str, account_id: &str) -> Result<(), KeyStoreError> {
+        let path = self.account_path(network_id, account_id)?;
+
+        remove_if_exists(fs::remove_file(default_key_path(&path)))?;
+        remove_if_exists(fs::remove_dir_all(path))
+    }
+
+    /// Removes a single key, whether it is the default key or an additional one.
+    pub fn remove_key_by_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<(), KeyStoreError> {
+        let path = self.account_path(network_id, account_id)?;
+        let default_path = default_key_path(&path);
+
+        if let Some(keypair) = read_key_file(&default_path)? {
+            if &keypair.public_key() == public_key {
+                fs::remove_file(&default_path)?;
+            }
+        }
+
+        remove_if_exists(fs::remove_file(path.join(public_key_file_name(public_key))))
+    }
+
+    pub fn get_networks(&self) -> Result<Vec<String>, KeyStoreError> {
+        let mut result = vec![];
+
+        for (name, path) in list_dir(&self.root)? {
+            if path.is_dir() {
+                result.push(name);
+            }
+        }
+        result.sort();
+
+        Ok(result)
+    }
+
+    pub fn get_accounts(&self, network_id: &str) -> Result<Vec<String>, KeyStoreError> {
+        let mut result = vec![];
+
+        for (name, path) in list_dir(&self.network_path(network_id)?)? {
+            let account_id = match path.is_dir() {
+                true => name,
+                false => match name.strip_suffix(".json") {
+                    Some(account_id) => account_id.to_string(),
+                    None => continue,
+                },
+            };
+            if !result.contains(&account_id) {
+                result.push(account_id);
+            }
+        }
+        result.sort();
+
+        Ok(result)
+    }
+
+    pub fn clear(&self) -> Result<(), KeyStoreError> {
+        for network_id in self.get_networks()? {
+            fs::remove_dir_all(self.network_path(&network_id)?)?;
+        }
+
+        Ok(())
+    }
+
+    fn network_path(&self, network_id: &str) -> Result<PathBuf, KeyStoreError> {
+        Ok(self.root.join(check_name(network_id)?))
+    }
+
+    fn account_path(&self, network_id: &str, account_id: &str) -> Result<PathBuf, KeyStoreError> {
+        Ok(self.network_path(network_id)?.join(check_name(account_id)?))
+    }
+
+    fn get_additional_keys(&self, account_path: &Path) -> Result<Vec<KeyPair>, KeyStoreError> {
+        let mut result = vec![];
+        let mut paths = list_dir(account_path)?
+            .into_iter()
+            .map(|(_, path)| path)
+            .filter(|path| path.extension() == Some(OsStr::new(KEY_FILE_EXTENSION)))
+            .collect::<Vec<PathBuf>>();
+        paths.sort();
+
+        for path in paths {
+            if let Some(keypair) = read_key_file(&path)? {
+                result.push(keypair);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Names become path segments, so anything that could escape the key store is rejected.
+fn check_name(name: &str) -> Result<&str, KeyStoreError> {
+    match name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+        true => Err(KeyStoreError::InvalidName {
+            name: name.to_string(),
+        }),
+        false => Ok(name),
+    }
+}
+
+/// `<account>.json`, the account id may contain dots so the extension is appended.
+fn default_key_path(account_path: &Path) -> PathBuf {
+    let mut path = account_path.as_os_str().to_owned();
+    path.push(".");
+    path.push(KEY_FILE_EXTENSION);
+
+    PathBuf::from(path)
+}
+
+/// `ed25519:abc` is saved as `ed25519_abc.json`, `:` is not allowed in Windows file names.
+fn public_key_file_name(public_key: &PublicKey) -> String {
+    format!(
+        "{}.{}",
+        public_key.to_string().replace(':', "_"),
+        KEY_FILE_EXTENSION
+    )
+}
+
+fn remove_if_exists(result: std::io::Result<()>) -> Result<(), KeyStoreError> {
+    match result {
+        Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+fn list_dir(path: &Path) -> Result<Vec<(String, PathBuf)>, KeyStoreError> {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err.into()),
+    };
+    let mut result = vec![];
+
+    for entry in entries {
+        let entry = entry?;
+        if let Some(name) = entry.file_name().to_str() {
+            result.push((name.to_string(), entry.path()));
+        }
+    }
+
+    Ok(result)
+}
+
+fn read_private_file(path: &Path) -> Result<Option<Zeroizing<String>>, KeyStoreError> {
+    match fs::read_to_string(path) {
+        Ok(data) => Ok(Some(Zeroizing::new(data))),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Writes a private temporary file next to `path` and renames it over `path`, so an interrupted
+/// write never leaves a truncated key file behind.
+fn write_private_file(path: &Path, data: &str) -> Result<(), KeyStoreError> {
+    if let Some(parent) = path.parent() {
+        create_private_dir(parent)?;
+    }
+    let temp_path = temp_file_path(path);
+    let result = write_new_private_file(&temp_path, data)
+        .and_then(|_| fs::rename(&temp_path, path).map_err(KeyStoreError::from));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
+fn write_new_private_file(path: &Path, data: &str) -> Result<(), KeyStoreError> {
+    remove_if_exists(fs::remove_file(path))?;
+    let mut file = open_private_file(path)?;
+    file.write_all(data.as_bytes())?;
+    file.sync_all()?;
+
+    Ok(())
+}
+
+/// `alice.testnet.json` is written as `.alice.testnet.json.<pid>.tmp`, which is not read as a key.
+fn temp_file_path(path: &Path) -> PathBuf {
+    let mut file_name = std::ffi::OsString::from(".");
+    file_name.push(path.file_name().unwrap_or_default());
+    file_name.push(format!(".{}.tmp", std::process::id()));
+
+    path.with_file_name(file_name)
+}
+
+fn read_key_file(path: &Path) -> Result<Option<KeyPair>, KeyStoreError> {
+    let data = match read_private_file(path)? {
+        Some(data) => data,
+        None => return Ok(None),
+    };
+    let invalid_key_file = |error_message: String| KeyStoreError::InvalidKeyFile {
+        path: path.display().to_string(),
+        error_message,
+    };
+    let key_file: KeyFile =
+        serde_json::from_str(&data).map_err(|err| invalid_key_file(err.to_string()))?;
+    let keypair = KeyPair::from_secret_key(&key_file.private_key)
+        .map_err(|err| invalid_key_file(err.to_string()))?;
+
+    match keypair.public_key().to_string() == key_file.public_key {
+        true => Ok(Some(keypair)),
+        false => Err(invalid_key_file(
+            "public key does not match the private key".to_string(),
+        )),
+    }
+}
+
+fn write_key_file(path: &Path, account_id: &str, keypair: &KeyPair) -> Result<(), KeyStoreError> {
+    let key_file = KeyFile {
+        account_id: account_id.to_string(),
+        public_key: keypair.public_key().to_string(),
+        private_key: keypair.to_secret_string().to_string(),
+    };
+    let data = Zeroizing::new(serde_json::to_string(&key_file).map_err(|err| {
+        KeyStoreError::InvalidKeyFile {
+            path: path.display().to_string(),
+            error_message: err.to_string(),
+        }
+    })?);
+
+    write_private_file(path, &data)
+}
+
+#[cfg(unix)]
+fn create_private_dir(path: &Path) -> Result<(), KeyStoreError> {
+    use std::os::unix::fs::DirBuilderExt;
+
+    fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(path)?;
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn create_private_dir(path: &Path) -> Result<(), KeyStoreError> {
+    fs::create_dir_all(path)?;
+
+    Ok(())
+}
+
+/// Creates a new file readable by its owner only.
+#[cfg(unix)]
+fn open_private_file(path: &Path) -> Result<fs::File, KeyStoreError> {
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)?;
+    file.set_permissions(fs::Permissions::from_mode(0o600))?;
+
+    Ok(file)
+}
+
+#[cfg(not(unix))]
+fn open_private_file(path: &Path) -> Result<fs::File, KeyStoreError> {
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?;
+
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::crypto::KeyType};
+
+    const NETWORK_ID: &str = "testnet";
+    const ACCOUNT_ID: &str = "alice.testnet";
+
+    #[test]
+    pub fn test_set_get_and_remove_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_store = FileSystemKeyStore::new(dir.path());
+        let keypair = KeyPair::from_random(KeyType::ED25519);
+
+        assert!(key_store.get_key(NETWORK_ID, ACCOUNT_ID).unwrap().is_none());
+        key_store
+            .set_key(NETWORK_ID, ACCOUNT_ID, keypair.clone())
+            .unwrap();
+
+        let path = dir.path().join(NETWORK_ID).join("alice.testnet.json");
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["account_id"], ACCOUNT_ID);
+        assert_eq!(json["public_key"], keypair.public_key().to_string());
+        assert_eq!(json["private_key"], keypair.to_secret_string().as_str());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode(&path), 0o600);
+            assert_eq!(mode(&dir.path().join(NETWORK_ID)), 0o700);
+        }
+
+        let stored = key_store.get_key(NETWORK_ID, ACCOUNT_ID).unwrap().unwrap();
+        assert_eq!(stored.public_key(), keypair.public_key());
+        assert_eq!(key_store.get_networks().unwrap(), vec![NETWORK_ID]);
+        assert_eq!(
+            key_store.get_accounts(NETWORK_ID).unwrap(),
+            vec![ACCOUNT_ID]
+        );
+
+        key_store.remove_key(NETWORK_ID, ACCOUNT_ID).unwrap();
+        assert!(key_store.get_key(NETWORK_ID, ACCOUNT_ID).unwrap().is_none());
+        assert!(key_store.get_accounts(NETWORK_ID).unwrap().is_empty());
+    }
+
+    #[test]
+    pub fn test_overwrite_key_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_store = FileSystemKeyStore::new(dir.path());
+        let keypair = KeyPair::from_random(KeyType::ED25519);
+
+        key_store
+            .set_key(
+                NETWORK_ID,
+                ACCOUNT_ID,
+                KeyPair::from_random(KeyType::ED25519),
+            )
+            .unwrap();
+        key_store
+            .set_key(NETWORK_ID, ACCOUNT_ID, keypair.clone())
+            .unwrap();
+
+        let names = list_dir(&dir.path().join(NETWORK_ID))
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<String>>();
+        assert_eq!(names, vec!["alice.testnet.json"]);
+        assert_eq!(
+            key_store
+                .get_key(NETWORK_ID, ACCOUNT_ID)
+                .unwrap()
+                .unwrap()
+                .public_key(),
+            keypair.public_key()
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let path = dir.path().join(NETWORK_ID).join("alice.testnet.json");
+            let mode = fs::metadata(path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode, 0o600);
+        }
+    }
+
+    #[test]
+    pub fn test_multiple_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_store = FileSystemKeyStore::new(dir.path());
+        let default_keypair = KeyPair::from_random(KeyType::ED25519);
+        let other_keypair = KeyPair::from_random(KeyType::ED25519);
+
+        key_store
+            .add_key(NETWORK_ID, ACCOUNT_ID, other_keypair.clone())
+            .unwrap();
+        assert_eq!(
+            key_store
+                .get_key(NETWORK_ID, ACCOUNT_ID)
+                .unwrap()
+                .unwrap()
+                .public_key(),
+            other_keypair.public_key()
+        );
+        assert!(dir
+            .path()
+            .join(NETWORK_ID)
+            .join(ACCOUNT_ID)
+            .join(public_key_file_name(&other_keypair.public_key()))
+            .exists());
+
+        key_store
+            .set_key(NETWORK_ID, ACCOUNT_ID, default_keypair.clone())
+            .unwrap();
+        let public_keys = key_store
+            .get_keys(NETWORK_ID, ACCOUNT_ID)
+            .unwrap()
+            .iter()
+            .map(|keypair| keypair.public_key())
+            .collect::<Vec<PublicKey>>();
+        assert_eq!(
+            public_keys,
+            vec![default_keypair.public_key(), other_keypair.public_key()]
+        );
+        assert_eq!(
+            key_store.get_accounts(NETWORK_ID).unwrap(),
+            vec![ACCOUNT_ID]
+        );
+
+        key_store
+            .remove_key_by_public_key(NETWORK_ID, ACCOUNT_ID, &default_keypair.public_key())
+            .unwrap();
+        assert_eq!(key_store.get_keys(NETWORK_ID, ACCOUNT_ID).unwrap().len(), 1);
+
+        key_store.clear().unwrap();
+        assert!(key_store.get_networks().unwrap().is_empty());
+    }
+
+    #[test]
+    pub fn test_read_near_cli_key_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let key_store = FileSystemKeyStore::new(dir.path());
+        let secret_key = "ed25519:2LRHYvi3uHHsADkF8HFRyZXJX3BG7HuQpuHw1cWcYSrivxNo5y76vkPA4ezKixS3jQ7e2zCfi4zfXbNAP72j2Ntk";
+
+        fs::create_dir_all(dir.path().join("mainnet")).unwrap();
+        fs::write(
+            dir.path().join("mainnet").join("bob.near.json"),
+            format!(
+                r#"{{"account_id":"bob.near","public_key":"ed25519:G9upgDmY9DPYvkxZrR52Foh7g351TKee1E4BJdCLfZaU","secret_key":"{}"}}"#,
+                secret_key
+            ),
+        )
+        .unwrap();
+
+        let keypair = key_store.get_key("mainnet", "bob.near").unwrap().unwrap();
+        assert_eq!(keypair.to_secret_string().as_str(), secret_key);
+        assert!(key_store.get_key("mainnet", "../mainnet").is_err());
+    }
+}
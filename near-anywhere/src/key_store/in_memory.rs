@@ -1,50 +1,128 @@
-use {super::storage_key_for_secret_key, crate::key_pair::KeyPair, hashbrown::HashMap};
+use {
+    super::{
+        parse_storage_key,
+        storage_key_for_public_key,
+        storage_key_for_secret_key,
+        KeyStoreError,
+    },
+    crate::{crypto::PublicKey, key_pair::KeyPair},
+    core::fmt::{Debug, Formatter},
+    hashbrown::HashMap,
+    std::sync::{Arc, RwLock},
+    zeroize::Zeroizing,
+};
 
-#[derive(Debug, Clone, PartialEq)]
+/// Keeps the keys in memory, clones share the same storage.
+#[derive(Clone, Default)]
 pub struct InMemoryKeyStore {
-    storage: HashMap<String, String>,
+    storage: Arc<RwLock<HashMap<String, Zeroizing<String>>>>,
 }
 
 impl InMemoryKeyStore {
     pub fn new() -> Self {
-        Self {
-            storage: HashMap::new(),
-        }
+        Self::default()
     }
 }
 
 impl InMemoryKeyStore {
-    pub fn set_key(&mut self, network_id: &str, account_id: &str, keypair: &KeyPair) {
-        let key = storage_key_for_secret_key(network_id, account_id);
+    pub fn set_key(&self, network_id: &str, account_id: &str, keypair: KeyPair) {
+        self.set_record(network_id, account_id, keypair.to_secret_string().as_str());
+    }
 
-        self.storage
-            .insert(key, keypair.to_string())
-            .expect("failed to set key pair");
+    /// Adds a key next to the default one, so an account can hold several keys.
+    pub fn add_key(&self, network_id: &str, account_id: &str, keypair: KeyPair) {
+        let key = storage_key_for_public_key(network_id, account_id, &keypair.public_key());
+
+        self.insert(key, keypair.to_secret_string());
     }
 
-    pub fn get_key(&self, network_id: &str, account_id: &str) -> Option<KeyPair> {
-        let key = storage_key_for_secret_key(network_id, account_id);
-        let value = self.storage.get(&key);
+    /// Default key of the account, or its first additional key when there is no default one.
+    pub fn get_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<KeyPair>, KeyStoreError> {
+        Ok(self.get_keys(network_id, account_id)?.into_iter().next())
+    }
 
-        match value {
-            Some(value) => Some(KeyPair::from_secret_key(&value).unwrap()),
-            None => None,
+    /// Default key first, followed by the additional keys.
+    pub fn get_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<KeyPair>, KeyStoreError> {
+        let mut keys = vec![storage_key_for_secret_key(network_id, account_id)];
+        keys.extend(self.additional_storage_keys(network_id, account_id));
+        let mut result: Vec<KeyPair> = vec![];
+
+        for key in keys {
+            if let Some(value) = self.get(&key) {
+                let keypair = parse_record(&value)?;
+                if !result
+                    .iter()
+                    .any(|item| item.public_key() == keypair.public_key())
+                {
+                    result.push(keypair);
+                }
+            }
         }
+
+        Ok(result)
     }
 
-    pub fn remove_key(&mut self, network_id: &str, account_id: &str) {
+    pub fn set_record(&self, network_id: &str, account_id: &str, record: &str) {
         let key = storage_key_for_secret_key(network_id, account_id);
-        self.storage.remove(&key).unwrap();
+
+        self.insert(key, Zeroizing::new(record.to_string()));
+    }
+
+    pub fn get_record(&self, network_id: &str, account_id: &str) -> Option<Zeroizing<String>> {
+        self.get(&storage_key_for_secret_key(network_id, account_id))
+    }
+
+    /// Removes the default key and every additional key of the account.
+    pub fn remove_key(&self, network_id: &str, account_id: &str) {
+        let mut keys = self.additional_storage_keys(network_id, account_id);
+        keys.push(storage_key_for_secret_key(network_id, account_id));
+        let mut storage = self.storage.write().expect("key store lock is poisoned");
+
+        for key in keys {
+            storage.remove(&key);
+        }
+    }
+
+    /// Removes a single key, whether it is the default key or an additional one.
+    pub fn remove_key_by_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<(), KeyStoreError> {
+        let default_key = storage_key_for_secret_key(network_id, account_id);
+        let is_default_key = match self.get(&default_key) {
+            Some(value) => &parse_record(&value)?.public_key() == public_key,
+            None => false,
+        };
+        let mut storage = self.storage.write().expect("key store lock is poisoned");
+
+        if is_default_key {
+            storage.remove(&default_key);
+        }
+        storage.remove(&storage_key_for_public_key(
+            network_id, account_id, public_key,
+        ));
+
+        Ok(())
     }
 
     pub fn get_networks(&self) -> Vec<String> {
         let mut result = vec![];
-        let keys = self.storage.keys();
 
-        for key in keys {
-            let parts = key.split(":").collect::<Vec<&str>>();
-            if parts.len() == 4 {
-                result.push(parts[3].to_string());
+        for key in self.storage_keys() {
+            if let Some((_, network_id, _)) = parse_storage_key(&key) {
+                if !result.iter().any(|item| item == network_id) {
+                    result.push(network_id.to_string());
+                }
             }
         }
 
@@ -53,19 +131,234 @@ impl InMemoryKeyStore {
 
     pub fn get_accounts(&self, network_id: &str) -> Vec<String> {
         let mut result = vec![];
-        let keys = self.storage.keys();
 
-        for key in keys {
-            let parts = key.split(":").collect::<Vec<&str>>();
-            if parts.len() == 4 && parts[3] == network_id {
-                result.push(parts[1].to_string());
+        for key in self.storage_keys() {
+            match parse_storage_key(&key) {
+                Some((account_id, key_network_id, _))
+                    if key_network_id == network_id
+                        && !result.iter().any(|item| item == account_id) =>
+                {
+                    result.push(account_id.to_string());
+                }
+                _ => {}
             }
         }
 
         result
     }
 
-    pub fn clear(&mut self) {
-        self.storage.clear();
+    pub fn clear(&self) {
+        self.storage
+            .write()
+            .expect("key store lock is poisoned")
+            .clear();
+    }
+
+    fn insert(&self, key: String, value: Zeroizing<String>) {
+        self.storage
+            .write()
+            .expect("key store lock is poisoned")
+            .insert(key, value);
+    }
+
+    fn get(&self, key: &str) -> Option<Zeroizing<String>> {
+        self.storage
+            .read()
+            .expect("key store lock is poisoned")
+            .get(key)
+            .cloned()
+    }
+
+    fn storage_keys(&self) -> Vec<String> {
+        self.storage
+            .read()
+            .expect("key store lock is poisoned")
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Storage keys of the additional keys of the account, sorted.
+    fn additional_storage_keys(&self, network_id: &str, account_id: &str) -> Vec<String> {
+        let mut result = self
+            .storage_keys()
+            .into_iter()
+            .filter(|key| {
+                matches!(
+                    parse_storage_key(key),
+                    Some((key_account_id, key_network_id, Some(_)))
+                        if key_account_id == account_id && key_network_id == network_id
+                )
+            })
+            .collect::<Vec<String>>();
+        result.sort();
+
+        result
+    }
+}
+
+fn parse_record(value: &str) -> Result<KeyPair, KeyStoreError> {
+    KeyPair::from_secret_key(value).map_err(|error| KeyStoreError::InvalidRecord {
+        error_message: error.to_string(),
+    })
+}
+
+impl PartialEq for InMemoryKeyStore {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.storage, &other.storage)
+    }
+}
+
+impl Debug for InMemoryKeyStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("InMemoryKeyStore")
+            .field("keys", &self.storage_keys())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{crypto::KeyType, key_store::KeyStore},
+    };
+
+    #[tokio::test]
+    pub async fn test_in_memory_key_store() {
+        let key_store = KeyStore::default();
+        let shared_key_store = key_store.clone();
+        let keypair = KeyPair::from_random(KeyType::ED25519);
+
+        key_store
+            .set_key("testnet", "alice.testnet", keypair.clone())
+            .await
+            .unwrap();
+        key_store
+            .set_key("testnet", "bob.testnet", keypair.clone())
+            .await
+            .unwrap();
+        key_store
+            .set_key("mainnet", "alice.near", keypair.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            shared_key_store
+                .get_key("testnet", "alice.testnet")
+                .await
+                .unwrap()
+                .unwrap()
+                .public_key(),
+            keypair.public_key()
+        );
+        let mut networks = shared_key_store.get_networks().await.unwrap();
+        networks.sort();
+        assert_eq!(networks, vec!["mainnet", "testnet"]);
+        let mut accounts = shared_key_store.get_accounts("testnet").await.unwrap();
+        accounts.sort();
+        assert_eq!(accounts, vec!["alice.testnet", "bob.testnet"]);
+
+        key_store
+            .remove_key("testnet", "alice.testnet")
+            .await
+            .unwrap();
+        assert!(shared_key_store
+            .get_key("testnet", "alice.testnet")
+            .await
+            .unwrap()
+            .is_none());
+
+        key_store.clear().await.unwrap();
+        assert!(shared_key_store.get_networks().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    pub async fn test_invalid_record() {
+        let key_store = KeyStore::default();
+        let keypair = KeyPair::from_random(KeyType::ED25519);
+
+        key_store
+            .set_record("testnet", "alice.testnet", "ed25519:invalid")
+            .await
+            .unwrap();
+        assert!(matches!(
+            key_store.get_keys("testnet", "alice.testnet").await,
+            Err(KeyStoreError::InvalidRecord { .. })
+        ));
+        assert!(key_store
+            .remove_key_by_public_key("testnet", "alice.testnet", &keypair.public_key())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    pub async fn test_multiple_keys() {
+        let key_store = KeyStore::default();
+        let default_keypair = KeyPair::from_random(KeyType::ED25519);
+        let other_keypair = KeyPair::from_random(KeyType::ED25519);
+
+        key_store
+            .add_key("testnet", "alice.testnet", other_keypair.clone())
+            .await
+            .unwrap();
+        key_store
+            .set_key("testnet", "alice.testnet", default_keypair.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            key_store
+                .get_public_keys("testnet", "alice.testnet")
+                .await
+                .unwrap(),
+            vec![default_keypair.public_key(), other_keypair.public_key()]
+        );
+        assert_eq!(
+            key_store.get_accounts("testnet").await.unwrap(),
+            vec!["alice.testnet"]
+        );
+        let signature = key_store
+            .sign_with_public_key(
+                "testnet",
+                "alice.testnet",
+                &other_keypair.public_key(),
+                &[7; 32],
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(other_keypair.verify(&[7; 32], signature));
+
+        key_store
+            .set_default_key("testnet", "alice.testnet", &other_keypair.public_key())
+            .await
+            .unwrap();
+        assert_eq!(
+            key_store
+                .get_public_keys("testnet", "alice.testnet")
+                .await
+                .unwrap(),
+            vec![other_keypair.public_key(), default_keypair.public_key()]
+        );
+
+        key_store
+            .remove_key_by_public_key("testnet", "alice.testnet", &other_keypair.public_key())
+            .await
+            .unwrap();
+        assert_eq!(
+            key_store
+                .get_key("testnet", "alice.testnet")
+                .await
+                .unwrap()
+                .unwrap()
+                .public_key(),
+            default_keypair.public_key()
+        );
+
+        key_store
+            .remove_key("testnet", "alice.testnet")
+            .await
+            .unwrap();
+        assert!(key_store.get_networks().await.unwrap().is_empty());
     }
 }
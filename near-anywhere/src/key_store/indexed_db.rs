@@ -0,0 +1,730 @@
+use {
+    super::{
+        parse_storage_key,
+        storage_key_for_public_key,
+        storage_key_for_secret_key,
+        KeyStoreError,
+    },
+    crate::{
+        crypto::{KeyType, PublicKey, SecretKey, Signature},
+        key_pair::KeyPair,
+    },
+    js_sys::{Array, Object, Promise, Reflect, Uint8Array},
+    std::str::FromStr,
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+    wasm_bindgen_futures::JsFuture,
+    web_sys::{
+        CryptoKey,
+        IdbDatabase,
+        IdbFactory,
+        IdbObjectStore,
+        IdbRequest,
+        IdbTransactionMode,
+        SubtleCrypto,
+    },
+    zeroize::Zeroizing,
+};
+
+const DATABASE_VERSION: u32 = 1;
+const OBJECT_STORE_NAME: &str = "keys";
+const PUBLIC_KEY_FIELD: &str = "publicKey";
+const PRIVATE_KEY_FIELD: &str = "privateKey";
+const ED25519_ALGORITHM: &str = "Ed25519";
+const ED25519_SEED_LENGTH: usize = 32;
+/// DER header of a PKCS#8 ed25519 private key, followed by the 32 bytes seed.
+const ED25519_PKCS8_PREFIX: [u8; 16] = [
+    0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22, 0x04, 0x20,
+];
+
+/// A keystore in IndexedDB, addressed with the same keys as the local storage keystore.
+///
+/// Keys are stored as strings, or with `with_web_crypto` as non-extractable WebCrypto keys which
+/// can sign through `KeyStore::sign` but can never be read back.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedDbKeyStore {
+    database: IdbDatabase,
+    use_web_crypto: bool,
+}
+
+impl IndexedDbKeyStore {
+    /// Opens the database `db_name`, creating it on first use.
+    pub async fn open(db_name: &str) -> Result<Self, KeyStoreError> {
+        let open_request = indexed_db()?
+            .open_with_u32(db_name, DATABASE_VERSION)
+            .map_err(js_error)?;
+        let upgrade_request = open_request.clone();
+        let on_upgrade_needed = Closure::once_into_js(move || {
+            if let Ok(database) = upgrade_request.result() {
+                let database: IdbDatabase = database.unchecked_into();
+                let _ = database.create_object_store(OBJECT_STORE_NAME);
+            }
+        });
+        open_request.set_onupgradeneeded(Some(on_upgrade_needed.unchecked_ref()));
+        let database = request_result(&open_request).await?;
+
+        Ok(Self {
+            database: database.unchecked_into(),
+            use_web_crypto: false,
+        })
+    }
+
+    /// Stores new ed25519 keys as non-extractable WebCrypto keys.
+    pub fn with_web_crypto(mut self, use_web_crypto: bool) -> Self {
+        self.use_web_crypto = use_web_crypto;
+        self
+    }
+
+    /// Whether the runtime can create ed25519 WebCrypto keys.
+    pub async fn supports_web_crypto() -> bool {
+        let generate_key = || -> Result<Promise, KeyStoreError> {
+            subtle_crypto()?
+                .generate_key_with_object(&ed25519_algorithm()?, false, &key_usages())
+                .map_err(js_error)
+        };
+
+        match generate_key() {
+            Ok(promise) => JsFuture::from(promise).await.is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    pub async fn set_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        keypair: KeyPair,
+    ) -> Result<(), KeyStoreError> {
+        let value = self.key_value(&keypair).await?;
+
+        self.put(&storage_key_for_secret_key(network_id, account_id), &value)
+            .await
+    }
+
+    /// Adds a key next to the default one, so an account can hold several keys.
+    pub async fn add_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        keypair: KeyPair,
+    ) -> Result<(), KeyStoreError> {
+        let key = storage_key_for_public_key(network_id, account_id, &keypair.public_key());
+        let value = self.key_value(&keypair).await?;
+
+        self.put(&key, &value).await
+    }
+
+    /// Default key of the account, or its first additional key when there is no default one.
+    /// Fails with `NonExtractable` for WebCrypto keys.
+    pub async fn get_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<KeyPair>, KeyStoreError> {
+        match self.get_stored_keys(network_id, account_id).await?.first() {
+            Some(stored_key) => Ok(Some(stored_key.to_key_pair(account_id)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Default key first, followed by the additional keys.
+    pub async fn get_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<KeyPair>, KeyStoreError> {
+        self.get_stored_keys(network_id, account_id)
+            .await?
+            .iter()
+            .map(|stored_key| stored_key.to_key_pair(account_id))
+            .collect()
+    }
+
+    pub async fn get_key_by_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<Option<KeyPair>, KeyStoreError> {
+        match self
+            .find_stored_key(network_id, account_id, public_key)
+            .await?
+        {
+            Some((_, stored_key)) => Ok(Some(stored_key.to_key_pair(account_id)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn get_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<PublicKey>, KeyStoreError> {
+        Ok(self
+            .get_public_keys(network_id, account_id)
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    pub async fn get_public_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<PublicKey>, KeyStoreError> {
+        Ok(self
+            .get_stored_keys(network_id, account_id)
+            .await?
+            .into_iter()
+            .map(|stored_key| stored_key.public_key)
+            .collect())
+    }
+
+    pub async fn sign(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        digest: &[u8; 32],
+    ) -> Result<Option<Signature>, KeyStoreError> {
+        match self.get_stored_keys(network_id, account_id).await?.first() {
+            Some(stored_key) => Ok(Some(stored_key.sign(digest).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn sign_with_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+        digest: &[u8; 32],
+    ) -> Result<Option<Signature>, KeyStoreError> {
+        match self
+            .find_stored_key(network_id, account_id, public_key)
+            .await?
+        {
+            Some((_, stored_key)) => Ok(Some(stored_key.sign(digest).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Makes a stored key the default one, the previous default key is kept as an additional key.
+    /// Entries are copied as they are, so WebCrypto keys never leave IndexedDB.
+    pub async fn set_default_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<(), KeyStoreError> {
+        let default_key = storage_key_for_secret_key(network_id, account_id);
+        let (key, stored_key) = self
+            .find_stored_key(network_id, account_id, public_key)
+            .await?
+            .ok_or_else(|| KeyStoreError::KeyNotFound {
+                account_id: account_id.to_string(),
+                public_key: public_key.to_string(),
+            })?;
+
+        if key == default_key {
+            return Ok(());
+        }
+        if let Some(default_stored_key) = self.get_stored_key(&default_key).await? {
+            let key =
+                storage_key_for_public_key(network_id, account_id, &default_stored_key.public_key);
+            self.put(&key, &default_stored_key.value).await?;
+        }
+
+        self.put(&default_key, &stored_key.value).await
+    }
+
+    pub async fn set_record(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        record: &str,
+    ) -> Result<(), KeyStoreError> {
+        self.put(
+            &storage_key_for_secret_key(network_id, account_id),
+            &JsValue::from_str(record),
+        )
+        .await
+    }
+
+    pub async fn get_record(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<Zeroizing<String>>, KeyStoreError> {
+        let value = self
+            .get(&storage_key_for_secret_key(network_id, account_id))
+            .await?;
+
+        if value.is_undefined() || value.is_null() {
+            return Ok(None);
+        }
+        match value.as_string() {
+            Some(record) => Ok(Some(Zeroizing::new(record))),
+            None => Err(KeyStoreError::NonExtractable {
+                account_id: account_id.to_string(),
+            }),
+        }
+    }
+
+    /// Removes the default key and every additional key of the account.
+    pub async fn remove_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<(), KeyStoreError> {
+        self.delete(&storage_key_for_secret_key(network_id, account_id))
+            .await?;
+
+        for key in self.additional_storage_keys(network_id, account_id).await? {
+            self.delete(&key).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes a single key, whether it is the default key or an additional one.
+    pub async fn remove_key_by_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<(), KeyStoreError> {
+        let default_key = storage_key_for_secret_key(network_id, account_id);
+
+        if let Some(stored_key) = self.get_stored_key(&default_key).await? {
+            if &stored_key.public_key == public_key {
+                self.delete(&default_key).await?;
+            }
+        }
+
+        self.delete(&storage_key_for_public_key(
+            network_id, account_id, public_key,
+        ))
+        .await
+    }
+
+    pub async fn get_networks(&self) -> Result<Vec<String>, KeyStoreError> {
+        let mut result = vec![];
+
+        for key in self.storage_keys().await? {
+            if let Some((_, network_id, _)) = parse_storage_key(&key) {
+                if !result.iter().any(|item| item == network_id) {
+                    result.push(network_id.to_string());
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub async fn get_accounts(&self, network_id: &str) -> Result<Vec<String>, KeyStoreError> {
+        let mut result = vec![];
+
+        for key in self.storage_keys().await? {
+            match parse_storage_key(&key) {
+                Some((account_id, key_network_id, _))
+                    if key_network_id == network_id
+                        && !result.iter().any(|item| item == account_id) =>
+                {
+                    result.push(account_id.to_string());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub async fn clear(&self) -> Result<(), KeyStoreError> {
+        let request = self
+            .object_store(IdbTransactionMode::Readwrite)?
+            .clear()
+            .map_err(js_error)?;
+
+        request_result(&request).await.map(|_| ())
+    }
+
+    /// The secret key, or with WebCrypto an object holding the imported key and its public key.
+    async fn key_value(&self, keypair: &KeyPair) -> Result<JsValue, KeyStoreError> {
+        if !self.use_web_crypto {
+            return Ok(JsValue::from_str(keypair.to_secret_string().as_str()));
+        }
+
+        let value = Object::new();
+        set_field(
+            &value,
+            PUBLIC_KEY_FIELD,
+            &JsValue::from_str(&keypair.public_key().to_string()),
+        )?;
+        set_field(&value, PRIVATE_KEY_FIELD, &import_key(keypair).await?)?;
+
+        Ok(value.into())
+    }
+
+    /// Default key first, followed by the additional keys.
+    async fn get_stored_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<StoredKey>, KeyStoreError> {
+        let mut keys = vec![storage_key_for_secret_key(network_id, account_id)];
+        keys.extend(self.additional_storage_keys(network_id, account_id).await?);
+        let mut result: Vec<StoredKey> = vec![];
+
+        for key in keys {
+            if let Some(stored_key) = self.get_stored_key(&key).await? {
+                if !result
+                    .iter()
+                    .any(|item| item.public_key == stored_key.public_key)
+                {
+                    result.push(stored_key);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Storage key and entry of the key, looked up as an additional key then as the default one.
+    async fn find_stored_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<Option<(String, StoredKey)>, KeyStoreError> {
+        let key = storage_key_for_public_key(network_id, account_id, public_key);
+        if let Some(stored_key) = self.get_stored_key(&key).await? {
+            return Ok(Some((key, stored_key)));
+        }
+
+        let key = storage_key_for_secret_key(network_id, account_id);
+        match self.get_stored_key(&key).await? {
+            Some(stored_key) if &stored_key.public_key == public_key => Ok(Some((key, stored_key))),
+            _ => Ok(None),
+        }
+    }
+
+    async fn get_stored_key(&self, key: &str) -> Result<Option<StoredKey>, KeyStoreError> {
+        StoredKey::from_value(self.get(key).await?)
+    }
+
+    fn object_store(&self, mode: IdbTransactionMode) -> Result<IdbObjectStore, KeyStoreError> {
+        self.database
+            .transaction_with_str_and_mode(OBJECT_STORE_NAME, mode)
+            .map_err(js_error)?
+            .object_store(OBJECT_STORE_NAME)
+            .map_err(js_error)
+    }
+
+    async fn put(&self, key: &str, value: &JsValue) -> Result<(), KeyStoreError> {
+        let request = self
+            .object_store(IdbTransactionMode::Readwrite)?
+            .put_with_key(value, &JsValue::from_str(key))
+            .map_err(js_error)?;
+
+        request_result(&request).await.map(|_| ())
+    }
+
+    async fn get(&self, key: &str) -> Result<JsValue, KeyStoreError> {
+        let request = self
+            .object_store(IdbTransactionMode::Readonly)?
+            .get(&JsValue::from_str(key))
+            .map_err(js_error)?;
+
+        request_result(&request).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), KeyStoreError> {
+        let request = self
+            .object_store(IdbTransactionMode::Readwrite)?
+            .delete(&JsValue::from_str(key))
+            .map_err(js_error)?;
+
+        request_result(&request).await.map(|_| ())
+    }
+
+    async fn storage_keys(&self) -> Result<Vec<String>, KeyStoreError> {
+        let request = self
+            .object_store(IdbTransactionMode::Readonly)?
+            .get_all_keys()
+            .map_err(js_error)?;
+        let keys: Array = request_result(&request).await?.unchecked_into();
+
+        Ok(keys.iter().filter_map(|key| key.as_string()).collect())
+    }
+
+    /// Storage keys of the additional keys of the account, sorted.
+    async fn additional_storage_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<String>, KeyStoreError> {
+        let mut result = self
+            .storage_keys()
+            .await?
+            .into_iter()
+            .filter(|key| {
+                matches!(
+                    parse_storage_key(key),
+                    Some((key_account_id, key_network_id, Some(_)))
+                        if key_account_id == account_id && key_network_id == network_id
+                )
+            })
+            .collect::<Vec<String>>();
+        result.sort();
+
+        Ok(result)
+    }
+}
+
+/// An IndexedDB entry holding a key, a secret key string or an object with a WebCrypto key.
+struct StoredKey {
+    public_key: PublicKey,
+    value: JsValue,
+}
+
+impl StoredKey {
+    fn from_value(value: JsValue) -> Result<Option<Self>, KeyStoreError> {
+        if value.is_undefined() || value.is_null() {
+            return Ok(None);
+        }
+
+        let public_key = match value.as_string().map(Zeroizing::new) {
+            Some(secret_key) => KeyPair::from_secret_key(&secret_key)
+                .map_err(invalid_record)?
+                .public_key(),
+            None => {
+                let public_key = get_field(&value, PUBLIC_KEY_FIELD)?
+                    .as_string()
+                    .ok_or_else(|| invalid_record("missing public key"))?;
+                PublicKey::from_str(&public_key).map_err(invalid_record)?
+            }
+        };
+
+        Ok(Some(StoredKey { public_key, value }))
+    }
+
+    fn to_key_pair(&self, account_id: &str) -> Result<KeyPair, KeyStoreError> {
+        match self.value.as_string().map(Zeroizing::new) {
+            Some(secret_key) => KeyPair::from_secret_key(&secret_key).map_err(invalid_record),
+            None => Err(KeyStoreError::NonExtractable {
+                account_id: account_id.to_string(),
+            }),
+        }
+    }
+
+    async fn sign(&self, digest: &[u8; 32]) -> Result<Signature, KeyStoreError> {
+        if let Some(secret_key) = self.value.as_string().map(Zeroizing::new) {
+            let keypair = KeyPair::from_secret_key(&secret_key).map_err(invalid_record)?;
+            return Ok(keypair.sign_prehashed(digest));
+        }
+
+        let private_key: CryptoKey = get_field(&self.value, PRIVATE_KEY_FIELD)?.unchecked_into();
+        let promise = subtle_crypto()?
+            .sign_with_object_and_buffer_source(
+                &ed25519_algorithm()?,
+                &private_key,
+                &Uint8Array::from(&digest[..]),
+            )
+            .map_err(js_error)?;
+        let signature = JsFuture::from(promise).await.map_err(js_error)?;
+        let signature = Uint8Array::new(&signature).to_vec();
+
+        Signature::from_parts(KeyType::ED25519, &signature).map_err(invalid_record)
+    }
+}
+
+fn js_error(error: JsValue) -> KeyStoreError {
+    KeyStoreError::Browser {
+        error_message: format!("{:?}", error),
+    }
+}
+
+fn invalid_record<E: ToString>(error: E) -> KeyStoreError {
+    KeyStoreError::InvalidRecord {
+        error_message: error.to_string(),
+    }
+}
+
+fn get_field(value: &JsValue, field: &str) -> Result<JsValue, KeyStoreError> {
+    Reflect::get(value, &JsValue::from_str(field)).map_err(js_error)
+}
+
+fn set_field(object: &Object, field: &str, value: &JsValue) -> Result<(), KeyStoreError> {
+    Reflect::set(object, &JsValue::from_str(field), value)
+        .map(|_| ())
+        .map_err(js_error)
+}
+
+/// `indexedDB` of the global scope, so it works in windows, workers and Node with a polyfill.
+fn indexed_db() -> Result<IdbFactory, KeyStoreError> {
+    let factory = get_field(&js_sys::global(), "indexedDB")?;
+
+    match factory.is_undefined() || factory.is_null() {
+        true => Err(KeyStoreError::Browser {
+            error_message: "IndexedDB is not available".to_string(),
+        }),
+        false => Ok(factory.unchecked_into()),
+    }
+}
+
+fn subtle_crypto() -> Result<SubtleCrypto, KeyStoreError> {
+    let crypto = get_field(&js_sys::global(), "crypto")?;
+
+    match crypto.is_undefined() || crypto.is_null() {
+        true => Err(KeyStoreError::Browser {
+            error_message: "WebCrypto is not available".to_string(),
+        }),
+        false => Ok(crypto.unchecked_into::<web_sys::Crypto>().subtle()),
+    }
+}
+
+fn ed25519_algorithm() -> Result<Object, KeyStoreError> {
+    let algorithm = Object::new();
+    set_field(&algorithm, "name", &JsValue::from_str(ED25519_ALGORITHM))?;
+
+    Ok(algorithm)
+}
+
+fn key_usages() -> Array {
+    Array::of1(&JsValue::from_str("sign"))
+}
+
+/// Imports the key as a non-extractable WebCrypto key, only ed25519 is supported.
+async fn import_key(keypair: &KeyPair) -> Result<JsValue, KeyStoreError> {
+    let seed = match keypair.secret_key() {
+        SecretKey::ED25519(secret_key) => &secret_key.0[..ED25519_SEED_LENGTH],
+        SecretKey::SECP256K1(_) => {
+            return Err(KeyStoreError::Browser {
+                error_message: "WebCrypto only stores ed25519 keys".to_string(),
+            })
+        }
+    };
+    let mut pkcs8 = Zeroizing::new(Vec::with_capacity(
+        ED25519_PKCS8_PREFIX.len() + ED25519_SEED_LENGTH,
+    ));
+    pkcs8.extend_from_slice(&ED25519_PKCS8_PREFIX);
+    pkcs8.extend_from_slice(seed);
+    let key_data = Uint8Array::from(pkcs8.as_slice());
+
+    let promise = subtle_crypto()?.import_key_with_object(
+        "pkcs8",
+        &key_data,
+        &ed25519_algorithm()?,
+        false,
+        &key_usages(),
+    );
+    let result = match promise {
+        Ok(promise) => JsFuture::from(promise).await,
+        Err(error) => Err(error),
+    };
+    // The JS copy of the key is not managed by Rust, wipe it as soon as it is imported.
+    key_data.fill(0, 0, key_data.length());
+
+    result.map_err(js_error)
+}
+
+/// Resolves with the result of the request once it succeeds.
+async fn request_result(request: &IdbRequest) -> Result<JsValue, KeyStoreError> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        let success_request = request.clone();
+        let on_success = Closure::once_into_js(move || {
+            let result = success_request.result().unwrap_or(JsValue::UNDEFINED);
+            let _ = resolve.call1(&JsValue::UNDEFINED, &result);
+        });
+        let error_request = request.clone();
+        let on_error = Closure::once_into_js(move || {
+            let error = get_field(&error_request, "error").unwrap_or(JsValue::UNDEFINED);
+            let _ = reject.call1(&JsValue::UNDEFINED, &error);
+        });
+
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+
+    JsFuture::from(promise).await.map_err(js_error)
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use {super::*, crate::key_store::KeyStore, wasm_bindgen_test::wasm_bindgen_test};
+
+    const NETWORK_ID: &str = "testnet";
+    const ACCOUNT_ID: &str = "alice.testnet";
+
+    #[wasm_bindgen_test]
+    async fn test_indexed_db_key_store() {
+        let key_store = KeyStore::indexed_db_key_store("near-anywhere-test")
+            .await
+            .unwrap();
+        let keypair = KeyPair::from_random(KeyType::ED25519);
+
+        key_store.clear().await.unwrap();
+        key_store
+            .set_key(NETWORK_ID, ACCOUNT_ID, keypair.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            key_store
+                .get_key(NETWORK_ID, ACCOUNT_ID)
+                .await
+                .unwrap()
+                .unwrap()
+                .public_key(),
+            keypair.public_key()
+        );
+        assert_eq!(key_store.get_networks().await.unwrap(), vec![NETWORK_ID]);
+        assert_eq!(
+            key_store.get_accounts(NETWORK_ID).await.unwrap(),
+            vec![ACCOUNT_ID]
+        );
+
+        key_store.remove_key(NETWORK_ID, ACCOUNT_ID).await.unwrap();
+        assert!(key_store
+            .get_key(NETWORK_ID, ACCOUNT_ID)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_web_crypto_key_is_not_extractable() {
+        if !IndexedDbKeyStore::supports_web_crypto().await {
+            return;
+        }
+        let key_store = KeyStore::IndexedDb(
+            IndexedDbKeyStore::open("near-anywhere-web-crypto-test")
+                .await
+                .unwrap()
+                .with_web_crypto(true),
+        );
+        let keypair = KeyPair::from_random(KeyType::ED25519);
+
+        key_store
+            .set_key(NETWORK_ID, ACCOUNT_ID, keypair.clone())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            key_store.get_key(NETWORK_ID, ACCOUNT_ID).await,
+            Err(KeyStoreError::NonExtractable { .. })
+        ));
+        assert_eq!(
+            key_store
+                .get_public_key(NETWORK_ID, ACCOUNT_ID)
+                .await
+                .unwrap(),
+            Some(keypair.public_key())
+        );
+        let signature = key_store
+            .sign(NETWORK_ID, ACCOUNT_ID, &[7; 32])
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(signature.verify_prehashed(&[7; 32], &keypair.public_key()));
+    }
+}
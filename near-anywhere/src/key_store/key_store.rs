@@ -1,13 +1,50 @@
 #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
 use super::BrowserLocalStorageKeyStore;
-use {super::InMemoryKeyStore, crate::key_pair::KeyPair};
+#[cfg(not(target_arch = "wasm32"))]
+use super::FileSystemKeyStore;
+#[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+use super::IndexedDbKeyStore;
+use {
+    super::{EncryptedKeyStore, InMemoryKeyStore, KeyStoreError, MergeKeyStore},
+    crate::{
+        crypto::{PublicKey, Signature},
+        key_pair::KeyPair,
+    },
+    core::{future::Future, pin::Pin},
+    zeroize::Zeroizing,
+};
+
+/// Future returned by the `KeyStore` methods, boxed as keystores can wrap other keystores.
+#[cfg(not(target_arch = "wasm32"))]
+pub type KeyStoreFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, KeyStoreError>> + Send + 'a>>;
+/// Future returned by the `KeyStore` methods, boxed as keystores can wrap other keystores.
+#[cfg(target_arch = "wasm32")]
+pub type KeyStoreFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, KeyStoreError>> + 'a>>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum KeyStore {
     /// A keystore that stores keys in a local storage.
     #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
     BrowserLocalStorage(BrowserLocalStorageKeyStore),
+    /// A keystore that stores keys in IndexedDB, optionally as non-extractable WebCrypto keys.
+    #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+    IndexedDb(IndexedDbKeyStore),
+    /// A keystore that stores keys in near-cli compatible credential files.
+    #[cfg(not(target_arch = "wasm32"))]
+    FileSystem(FileSystemKeyStore),
     InMemoryStorage(InMemoryKeyStore),
+    /// A keystore that encrypts the keys of another keystore with a passphrase.
+    Encrypted(EncryptedKeyStore),
+    /// A keystore that reads from several keystores in order and writes to a primary one.
+    Merge(MergeKeyStore),
+}
+
+impl Default for KeyStore {
+    /// The in-memory keystore, shared between clones.
+    fn default() -> Self {
+        Self::in_memory_key_store()
+    }
 }
 
 impl KeyStore {
@@ -24,57 +61,512 @@ impl KeyStore {
         KeyStore::BrowserLocalStorage(BrowserLocalStorageKeyStore::new())
     }
 
-    pub fn set_key(&self, network_id: &str, account_id: &str, keypair: KeyPair) {
-        match self {
-            #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
-            KeyStore::BrowserLocalStorage(key_store) => {
-                key_store.set_key(network_id, account_id, keypair);
+    /// Opens the IndexedDB database `db_name`, creating it on first use.
+    #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+    pub async fn indexed_db_key_store(db_name: &str) -> Result<Self, KeyStoreError> {
+        Ok(KeyStore::IndexedDb(IndexedDbKeyStore::open(db_name).await?))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn file_system_key_store<P: Into<std::path::PathBuf>>(root: P) -> Self {
+        KeyStore::FileSystem(FileSystemKeyStore::new(root))
+    }
+
+    /// The returned keystore is locked, keep a clone of the inner `EncryptedKeyStore` to unlock it.
+    pub fn encrypted_key_store(inner: KeyStore) -> Self {
+        KeyStore::Encrypted(EncryptedKeyStore::new(inner))
+    }
+
+    /// Reads from `key_stores` in order and writes to the first one.
+    pub fn merge_key_store(key_stores: Vec<KeyStore>) -> Result<Self, KeyStoreError> {
+        Ok(KeyStore::Merge(MergeKeyStore::new(key_stores)?))
+    }
+
+    /// Sets the default key of the account, its additional keys are kept.
+    pub fn set_key<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+        keypair: KeyPair,
+    ) -> KeyStoreFuture<'a, ()> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => {
+                    key_store.set_key(network_id, account_id, keypair);
+                    Ok(())
+                }
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store.set_key(network_id, account_id, keypair).await
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => {
+                    key_store.set_key(network_id, account_id, keypair)
+                }
+                KeyStore::InMemoryStorage(key_store) => {
+                    key_store.set_key(network_id, account_id, keypair);
+                    Ok(())
+                }
+                KeyStore::Encrypted(key_store) => {
+                    key_store.set_key(network_id, account_id, keypair).await
+                }
+                KeyStore::Merge(key_store) => {
+                    key_store.set_key(network_id, account_id, keypair).await
+                }
             }
-            _ => {}
-        }
+        })
     }
 
-    pub fn get_key(&self, network_id: &str, account_id: &str) -> Option<KeyPair> {
-        match self {
-            #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
-            KeyStore::BrowserLocalStorage(key_store) => key_store.get_key(network_id, account_id),
-            _ => None,
-        }
+    /// Adds a key next to the default one, so an account can hold several keys.
+    pub fn add_key<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+        keypair: KeyPair,
+    ) -> KeyStoreFuture<'a, ()> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => {
+                    key_store.add_key(network_id, account_id, keypair);
+                    Ok(())
+                }
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store.add_key(network_id, account_id, keypair).await
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => {
+                    key_store.add_key(network_id, account_id, keypair)
+                }
+                KeyStore::InMemoryStorage(key_store) => {
+                    key_store.add_key(network_id, account_id, keypair);
+                    Ok(())
+                }
+                KeyStore::Encrypted(key_store) => {
+                    key_store.add_key(network_id, account_id, keypair).await
+                }
+                KeyStore::Merge(key_store) => {
+                    key_store.add_key(network_id, account_id, keypair).await
+                }
+            }
+        })
     }
 
-    pub fn remove_key(&self, network_id: &str, account_id: &str) {
-        match self {
-            #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
-            KeyStore::BrowserLocalStorage(key_store) => {
-                key_store.remove_key(network_id, account_id);
+    /// Default key of the account, or its first additional key when there is no default one.
+    pub fn get_key<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+    ) -> KeyStoreFuture<'a, Option<KeyPair>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => {
+                    Ok(key_store.get_key(network_id, account_id))
+                }
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => key_store.get_key(network_id, account_id).await,
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => key_store.get_key(network_id, account_id),
+                KeyStore::InMemoryStorage(key_store) => key_store.get_key(network_id, account_id),
+                KeyStore::Encrypted(key_store) => key_store.get_key(network_id, account_id).await,
+                KeyStore::Merge(key_store) => key_store.get_key(network_id, account_id).await,
             }
-            _ => {}
-        }
+        })
     }
 
-    pub fn get_networks(&self) -> Vec<String> {
-        match self {
-            #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
-            KeyStore::BrowserLocalStorage(key_store) => key_store.get_networks(),
-            _ => vec![],
-        }
+    /// Default key first, followed by the additional keys.
+    pub fn get_keys<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+    ) -> KeyStoreFuture<'a, Vec<KeyPair>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => {
+                    Ok(key_store.get_keys(network_id, account_id))
+                }
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => key_store.get_keys(network_id, account_id).await,
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => key_store.get_keys(network_id, account_id),
+                KeyStore::InMemoryStorage(key_store) => key_store.get_keys(network_id, account_id),
+                KeyStore::Encrypted(key_store) => key_store.get_keys(network_id, account_id).await,
+                KeyStore::Merge(key_store) => key_store.get_keys(network_id, account_id).await,
+            }
+        })
     }
 
-    pub fn get_accounts(&self, network_id: &str) -> Vec<String> {
-        match self {
-            #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
-            KeyStore::BrowserLocalStorage(key_store) => key_store.get_accounts(network_id),
-            _ => vec![],
-        }
+    pub fn get_key_by_public_key<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+        public_key: &'a PublicKey,
+    ) -> KeyStoreFuture<'a, Option<KeyPair>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store
+                        .get_key_by_public_key(network_id, account_id, public_key)
+                        .await
+                }
+                KeyStore::Merge(key_store) => {
+                    key_store
+                        .get_key_by_public_key(network_id, account_id, public_key)
+                        .await
+                }
+                _ => Ok(self
+                    .get_keys(network_id, account_id)
+                    .await?
+                    .into_iter()
+                    .find(|keypair| &keypair.public_key() == public_key)),
+            }
+        })
+    }
+
+    /// Public key of the account, also available for keys that cannot be exported.
+    pub fn get_public_key<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+    ) -> KeyStoreFuture<'a, Option<PublicKey>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store.get_public_key(network_id, account_id).await
+                }
+                KeyStore::Merge(key_store) => {
+                    key_store.get_public_key(network_id, account_id).await
+                }
+                _ => Ok(self
+                    .get_key(network_id, account_id)
+                    .await?
+                    .map(|keypair| keypair.public_key())),
+            }
+        })
+    }
+
+    /// Public keys of the account, the default key first. Also available for keys that cannot be
+    /// exported, and for an encrypted keystore while it is locked.
+    pub fn get_public_keys<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+    ) -> KeyStoreFuture<'a, Vec<PublicKey>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store.get_public_keys(network_id, account_id).await
+                }
+                KeyStore::Encrypted(key_store) => {
+                    key_store.get_public_keys(network_id, account_id).await
+                }
+                KeyStore::Merge(key_store) => {
+                    key_store.get_public_keys(network_id, account_id).await
+                }
+                _ => Ok(self
+                    .get_keys(network_id, account_id)
+                    .await?
+                    .iter()
+                    .map(|keypair| keypair.public_key())
+                    .collect()),
+            }
+        })
+    }
+
+    /// Signs the 32 bytes `digest` with the key of the account, without exporting it from the
+    /// keystore, see `KeyPair::sign_prehashed`.
+    pub fn sign<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+        digest: &'a [u8; 32],
+    ) -> KeyStoreFuture<'a, Option<Signature>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store.sign(network_id, account_id, digest).await
+                }
+                KeyStore::Merge(key_store) => key_store.sign(network_id, account_id, digest).await,
+                _ => Ok(self
+                    .get_key(network_id, account_id)
+                    .await?
+                    .map(|keypair| keypair.sign_prehashed(digest))),
+            }
+        })
+    }
+
+    /// Signs the 32 bytes `digest` with the key of `public_key`, without exporting it from the
+    /// keystore.
+    pub fn sign_with_public_key<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+        public_key: &'a PublicKey,
+        digest: &'a [u8; 32],
+    ) -> KeyStoreFuture<'a, Option<Signature>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store
+                        .sign_with_public_key(network_id, account_id, public_key, digest)
+                        .await
+                }
+                KeyStore::Merge(key_store) => {
+                    key_store
+                        .sign_with_public_key(network_id, account_id, public_key, digest)
+                        .await
+                }
+                _ => Ok(self
+                    .get_key_by_public_key(network_id, account_id, public_key)
+                    .await?
+                    .map(|keypair| keypair.sign_prehashed(digest))),
+            }
+        })
+    }
+
+    /// Makes a stored key the default one, the previous default key is kept as an additional key.
+    pub fn set_default_key<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+        public_key: &'a PublicKey,
+    ) -> KeyStoreFuture<'a, ()> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store
+                        .set_default_key(network_id, account_id, public_key)
+                        .await
+                }
+                KeyStore::Encrypted(key_store) => {
+                    key_store
+                        .set_default_key(network_id, account_id, public_key)
+                        .await
+                }
+                _ => {
+                    let keypair = self
+                        .get_key_by_public_key(network_id, account_id, public_key)
+                        .await?
+                        .ok_or_else(|| KeyStoreError::KeyNotFound {
+                            account_id: account_id.to_string(),
+                            public_key: public_key.to_string(),
+                        })?;
+
+                    // The previous default key is added first, so it is never lost.
+                    if let Some(default_keypair) = self.get_key(network_id, account_id).await? {
+                        if &default_keypair.public_key() != public_key {
+                            self.add_key(network_id, account_id, default_keypair)
+                                .await?;
+                        }
+                    }
+
+                    self.set_key(network_id, account_id, keypair).await
+                }
+            }
+        })
+    }
+
+    /// Removes the default key and every additional key of the account.
+    pub fn remove_key<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+    ) -> KeyStoreFuture<'a, ()> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => {
+                    key_store.remove_key(network_id, account_id);
+                    Ok(())
+                }
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store.remove_key(network_id, account_id).await
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => key_store.remove_key(network_id, account_id),
+                KeyStore::InMemoryStorage(key_store) => {
+                    key_store.remove_key(network_id, account_id);
+                    Ok(())
+                }
+                KeyStore::Encrypted(key_store) => {
+                    key_store.remove_key(network_id, account_id).await
+                }
+                KeyStore::Merge(key_store) => key_store.remove_key(network_id, account_id).await,
+            }
+        })
+    }
+
+    /// Removes a single key, whether it is the default key or an additional one.
+    pub fn remove_key_by_public_key<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+        public_key: &'a PublicKey,
+    ) -> KeyStoreFuture<'a, ()> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => {
+                    key_store.remove_key_by_public_key(network_id, account_id, public_key);
+                    Ok(())
+                }
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store
+                        .remove_key_by_public_key(network_id, account_id, public_key)
+                        .await
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => {
+                    key_store.remove_key_by_public_key(network_id, account_id, public_key)
+                }
+                KeyStore::InMemoryStorage(key_store) => {
+                    key_store.remove_key_by_public_key(network_id, account_id, public_key)
+                }
+                KeyStore::Encrypted(key_store) => {
+                    key_store
+                        .remove_key_by_public_key(network_id, account_id, public_key)
+                        .await
+                }
+                KeyStore::Merge(key_store) => {
+                    key_store
+                        .remove_key_by_public_key(network_id, account_id, public_key)
+                        .await
+                }
+            }
+        })
+    }
+
+    pub fn get_networks(&self) -> KeyStoreFuture<'_, Vec<String>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => Ok(key_store.get_networks()),
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => key_store.get_networks().await,
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => key_store.get_networks(),
+                KeyStore::InMemoryStorage(key_store) => Ok(key_store.get_networks()),
+                KeyStore::Encrypted(key_store) => key_store.get_networks().await,
+                KeyStore::Merge(key_store) => key_store.get_networks().await,
+            }
+        })
+    }
+
+    pub fn get_accounts<'a>(&'a self, network_id: &'a str) -> KeyStoreFuture<'a, Vec<String>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => Ok(key_store.get_accounts(network_id)),
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => key_store.get_accounts(network_id).await,
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => key_store.get_accounts(network_id),
+                KeyStore::InMemoryStorage(key_store) => Ok(key_store.get_accounts(network_id)),
+                KeyStore::Encrypted(key_store) => key_store.get_accounts(network_id).await,
+                KeyStore::Merge(key_store) => key_store.get_accounts(network_id).await,
+            }
+        })
+    }
+
+    pub fn clear(&self) -> KeyStoreFuture<'_, ()> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => {
+                    key_store.clear();
+                    Ok(())
+                }
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => key_store.clear().await,
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => key_store.clear(),
+                KeyStore::InMemoryStorage(key_store) => {
+                    key_store.clear();
+                    Ok(())
+                }
+                KeyStore::Encrypted(key_store) => key_store.clear().await,
+                KeyStore::Merge(key_store) => key_store.clear().await,
+            }
+        })
+    }
+
+    /// Stores `record` in place of the secret key, so wrapping keystores can store their own
+    /// format. An encrypted keystore passes records through to its inner keystore.
+    pub fn set_record<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+        record: &'a str,
+    ) -> KeyStoreFuture<'a, ()> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => {
+                    key_store.set_record(network_id, account_id, record);
+                    Ok(())
+                }
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store.set_record(network_id, account_id, record).await
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => {
+                    key_store.set_record(network_id, account_id, record)
+                }
+                KeyStore::InMemoryStorage(key_store) => {
+                    key_store.set_record(network_id, account_id, record);
+                    Ok(())
+                }
+                KeyStore::Encrypted(key_store) => {
+                    key_store
+                        .inner()
+                        .set_record(network_id, account_id, record)
+                        .await
+                }
+                KeyStore::Merge(key_store) => {
+                    key_store.set_record(network_id, account_id, record).await
+                }
+            }
+        })
     }
 
-    pub fn clear(&self) {
-        match self {
-            #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
-            KeyStore::BrowserLocalStorage(key_store) => {
-                key_store.clear();
+    pub fn get_record<'a>(
+        &'a self,
+        network_id: &'a str,
+        account_id: &'a str,
+    ) -> KeyStoreFuture<'a, Option<Zeroizing<String>>> {
+        Box::pin(async move {
+            match self {
+                #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
+                KeyStore::BrowserLocalStorage(key_store) => {
+                    Ok(key_store.get_record(network_id, account_id))
+                }
+                #[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+                KeyStore::IndexedDb(key_store) => {
+                    key_store.get_record(network_id, account_id).await
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                KeyStore::FileSystem(key_store) => key_store.get_record(network_id, account_id),
+                KeyStore::InMemoryStorage(key_store) => {
+                    Ok(key_store.get_record(network_id, account_id))
+                }
+                KeyStore::Encrypted(key_store) => {
+                    key_store.inner().get_record(network_id, account_id).await
+                }
+                KeyStore::Merge(key_store) => key_store.get_record(network_id, account_id).await,
             }
-            _ => {}
-        }
+        })
     }
 }
@@ -0,0 +1,368 @@
+use {
+    super::{KeyStore, KeyStoreError},
+    crate::{
+        crypto::{PublicKey, Signature},
+        key_pair::KeyPair,
+    },
+    zeroize::Zeroizing,
+};
+
+/// Chains several key stores, keys are looked up in order and written to the primary one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeKeyStore {
+    key_stores: Vec<KeyStore>,
+    write_key_store_index: usize,
+}
+
+impl MergeKeyStore {
+    /// The first key store is the primary one, fails without any key store.
+    pub fn new(key_stores: Vec<KeyStore>) -> Result<Self, KeyStoreError> {
+        if key_stores.is_empty() {
+            return Err(KeyStoreError::NoKeyStores);
+        }
+
+        Ok(Self {
+            key_stores,
+            write_key_store_index: 0,
+        })
+    }
+
+    pub fn with_write_key_store_index(
+        mut self,
+        write_key_store_index: usize,
+    ) -> Result<Self, KeyStoreError> {
+        if write_key_store_index >= self.key_stores.len() {
+            return Err(KeyStoreError::InvalidKeyStoreIndex {
+                index: write_key_store_index,
+                len: self.key_stores.len(),
+            });
+        }
+        self.write_key_store_index = write_key_store_index;
+
+        Ok(self)
+    }
+
+    pub fn key_stores(&self) -> &[KeyStore] {
+        &self.key_stores
+    }
+
+    pub fn primary_key_store(&self) -> &KeyStore {
+        &self.key_stores[self.write_key_store_index]
+    }
+
+    pub async fn set_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        keypair: KeyPair,
+    ) -> Result<(), KeyStoreError> {
+        self.primary_key_store()
+            .set_key(network_id, account_id, keypair)
+            .await
+    }
+
+    pub async fn add_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        keypair: KeyPair,
+    ) -> Result<(), KeyStoreError> {
+        self.primary_key_store()
+            .add_key(network_id, account_id, keypair)
+            .await
+    }
+
+    pub async fn get_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<KeyPair>, KeyStoreError> {
+        for key_store in self.key_stores.iter() {
+            if let Some(keypair) = key_store.get_key(network_id, account_id).await? {
+                return Ok(Some(keypair));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Keys of every key store, in order.
+    pub async fn get_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<KeyPair>, KeyStoreError> {
+        let mut result: Vec<KeyPair> = vec![];
+
+        for key_store in self.key_stores.iter() {
+            for keypair in key_store.get_keys(network_id, account_id).await? {
+                if !result
+                    .iter()
+                    .any(|item| item.public_key() == keypair.public_key())
+                {
+                    result.push(keypair);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub async fn get_key_by_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<Option<KeyPair>, KeyStoreError> {
+        for key_store in self.key_stores.iter() {
+            if let Some(keypair) = key_store
+                .get_key_by_public_key(network_id, account_id, public_key)
+                .await?
+            {
+                return Ok(Some(keypair));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub async fn get_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<PublicKey>, KeyStoreError> {
+        for key_store in self.key_stores.iter() {
+            if let Some(public_key) = key_store.get_public_key(network_id, account_id).await? {
+                return Ok(Some(public_key));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub async fn get_public_keys(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Vec<PublicKey>, KeyStoreError> {
+        let mut result = vec![];
+
+        for key_store in self.key_stores.iter() {
+            for public_key in key_store.get_public_keys(network_id, account_id).await? {
+                if !result.contains(&public_key) {
+                    result.push(public_key);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Signs with the first key store holding a key for the account.
+    pub async fn sign(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        digest: &[u8; 32],
+    ) -> Result<Option<Signature>, KeyStoreError> {
+        for key_store in self.key_stores.iter() {
+            if let Some(signature) = key_store.sign(network_id, account_id, digest).await? {
+                return Ok(Some(signature));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub async fn sign_with_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+        digest: &[u8; 32],
+    ) -> Result<Option<Signature>, KeyStoreError> {
+        for key_store in self.key_stores.iter() {
+            if let Some(signature) = key_store
+                .sign_with_public_key(network_id, account_id, public_key, digest)
+                .await?
+            {
+                return Ok(Some(signature));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Removes the key from every key store.
+    pub async fn remove_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<(), KeyStoreError> {
+        for key_store in self.key_stores.iter() {
+            key_store.remove_key(network_id, account_id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the key from every key store.
+    pub async fn remove_key_by_public_key(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<(), KeyStoreError> {
+        for key_store in self.key_stores.iter() {
+            key_store
+                .remove_key_by_public_key(network_id, account_id, public_key)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_networks(&self) -> Result<Vec<String>, KeyStoreError> {
+        let mut result = vec![];
+
+        for key_store in self.key_stores.iter() {
+            for network_id in key_store.get_networks().await? {
+                if !result.contains(&network_id) {
+                    result.push(network_id);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub async fn get_accounts(&self, network_id: &str) -> Result<Vec<String>, KeyStoreError> {
+        let mut result = vec![];
+
+        for key_store in self.key_stores.iter() {
+            for account_id in key_store.get_accounts(network_id).await? {
+                if !result.contains(&account_id) {
+                    result.push(account_id);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub async fn clear(&self) -> Result<(), KeyStoreError> {
+        for key_store in self.key_stores.iter() {
+            key_store.clear().await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn set_record(
+        &self,
+        network_id: &str,
+        account_id: &str,
+        record: &str,
+    ) -> Result<(), KeyStoreError> {
+        self.primary_key_store()
+            .set_record(network_id, account_id, record)
+            .await
+    }
+
+    pub async fn get_record(
+        &self,
+        network_id: &str,
+        account_id: &str,
+    ) -> Result<Option<Zeroizing<String>>, KeyStoreError> {
+        for key_store in self.key_stores.iter() {
+            if let Some(record) = key_store.get_record(network_id, account_id).await? {
+                return Ok(Some(record));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::crypto::KeyType};
+
+    #[tokio::test]
+    pub async fn test_merge_key_store() {
+        let primary = KeyStore::in_memory_key_store();
+        let secondary = KeyStore::in_memory_key_store();
+        let key_store = MergeKeyStore::new(vec![primary.clone(), secondary.clone()])
+            .unwrap()
+            .with_write_key_store_index(1)
+            .unwrap();
+        let primary_keypair = KeyPair::from_random(KeyType::ED25519);
+        let secondary_keypair = KeyPair::from_random(KeyType::ED25519);
+
+        primary
+            .set_key("testnet", "alice.testnet", primary_keypair.clone())
+            .await
+            .unwrap();
+        secondary
+            .set_key("testnet", "alice.testnet", secondary_keypair)
+            .await
+            .unwrap();
+        key_store
+            .set_key(
+                "mainnet",
+                "bob.near",
+                KeyPair::from_random(KeyType::ED25519),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            key_store
+                .get_key("testnet", "alice.testnet")
+                .await
+                .unwrap()
+                .unwrap()
+                .public_key(),
+            primary_keypair.public_key()
+        );
+        assert!(secondary
+            .get_key("mainnet", "bob.near")
+            .await
+            .unwrap()
+            .is_some());
+        assert!(primary
+            .get_key("mainnet", "bob.near")
+            .await
+            .unwrap()
+            .is_none());
+
+        let mut networks = key_store.get_networks().await.unwrap();
+        networks.sort();
+        assert_eq!(networks, vec!["mainnet", "testnet"]);
+        assert_eq!(
+            key_store.get_accounts("testnet").await.unwrap(),
+            vec!["alice.testnet"]
+        );
+
+        key_store
+            .remove_key("testnet", "alice.testnet")
+            .await
+            .unwrap();
+        assert!(key_store
+            .get_key("testnet", "alice.testnet")
+            .await
+            .unwrap()
+            .is_none());
+
+        assert!(matches!(
+            MergeKeyStore::new(vec![]),
+            Err(KeyStoreError::NoKeyStores)
+        ));
+        assert!(matches!(
+            MergeKeyStore::new(vec![primary])
+                .unwrap()
+                .with_write_key_store_index(1),
+            Err(KeyStoreError::InvalidKeyStoreIndex { index: 1, len: 1 })
+        ));
+    }
+}
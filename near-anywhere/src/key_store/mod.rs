@@ -1,14 +1,60 @@
+use crate::crypto::PublicKey;
+
 #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
 mod browser_local_storage;
+mod encrypted;
+mod errors;
+#[cfg(not(target_arch = "wasm32"))]
+mod file_system;
 mod in_memory;
+#[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+mod indexed_db;
 mod key_store;
+mod merge;
 
 #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
 pub use browser_local_storage::BrowserLocalStorageKeyStore;
-pub use {in_memory::InMemoryKeyStore, key_store::KeyStore};
+#[cfg(not(target_arch = "wasm32"))]
+pub use file_system::{FileSystemKeyStore, NEAR_CREDENTIALS_DIR};
+#[cfg(all(target_arch = "wasm32", feature = "indexed_db"))]
+pub use indexed_db::IndexedDbKeyStore;
+pub use {
+    encrypted::{EncryptedKeyStore, ScryptParams, ENCRYPTED_KEY_STORE_VERSION},
+    errors::KeyStoreError,
+    in_memory::InMemoryKeyStore,
+    key_store::KeyStore,
+    merge::MergeKeyStore,
+};
 
 const LOCAL_STORAGE_KEY_PREFIX: &str = "near-anywhere:keystore";
 
+/// Storage key of the default key of the account.
 pub fn storage_key_for_secret_key(network_id: &str, account_id: &str) -> String {
     format!("{}:{}:{}", LOCAL_STORAGE_KEY_PREFIX, account_id, network_id)
 }
+
+/// Storage key of a key indexed by its public key, kept next to the default key of the account.
+pub fn storage_key_for_public_key(
+    network_id: &str,
+    account_id: &str,
+    public_key: &PublicKey,
+) -> String {
+    format!(
+        "{}:{}",
+        storage_key_for_secret_key(network_id, account_id),
+        public_key
+    )
+}
+
+/// Splits a storage key into the account id, the network id and the public key of keys indexed
+/// by public key. Account ids and network ids never contain `:`, public keys do.
+pub fn parse_storage_key(key: &str) -> Option<(&str, &str, Option<&str>)> {
+    let mut parts = key
+        .strip_prefix(LOCAL_STORAGE_KEY_PREFIX)?
+        .strip_prefix(':')?
+        .splitn(3, ':');
+    let account_id = parts.next()?;
+    let network_id = parts.next()?;
+
+    Some((account_id, network_id, parts.next()))
+}
@@ -14,9 +14,15 @@ pub use near_anywhere_primitives::{
 };
 
 pub mod account;
+pub mod contract;
+pub mod fungible_token;
 pub mod key_pair;
 pub mod key_store;
+pub mod non_fungible_token;
+pub mod seed_phrase;
 pub mod signer;
+pub mod staking_pool;
+pub mod storage_management;
 pub mod transaction;
 
 #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
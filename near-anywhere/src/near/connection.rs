@@ -1,12 +1,13 @@
 use {
     super::{errors::ConnectionError, NearConfig},
     crate::{client::Transport, signer::Signer},
+    std::sync::Arc,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Connection {
     pub network_id: String,
-    pub signer: Signer,
+    pub signer: Arc<dyn Signer>,
     pub jsvm_account_id: String,
     pub transport: Transport,
 }
@@ -15,7 +16,7 @@ impl Connection {
     pub fn new(
         network_id: &str,
         transport: Transport,
-        signer: Signer,
+        signer: Arc<dyn Signer>,
         jsvm_account_id: &str,
     ) -> Self {
         Self {
@@ -27,6 +28,16 @@ impl Connection {
     }
 }
 
+// Signers have no structural equality, connections are equal if they share the same signer.
+impl PartialEq for Connection {
+    fn eq(&self, other: &Self) -> bool {
+        self.network_id == other.network_id
+            && Arc::ptr_eq(&self.signer, &other.signer)
+            && self.jsvm_account_id == other.jsvm_account_id
+            && self.transport == other.transport
+    }
+}
+
 impl TryFrom<NearConfig> for Connection {
     type Error = ConnectionError;
 
@@ -1,20 +1,21 @@
 use {
-    crate::{client::Transport, key_store::KeyStore, signer::Signer},
+    crate::{client::Transport, key_store::KeyStore, primitives::types::NearToken, signer::Signer},
     hashbrown::HashMap,
+    std::sync::Arc,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct NearConfig {
     pub explorer_url: Option<String>,
     pub headers: HashMap<String, String>,
     pub helper_url: Option<String>,
-    pub initial_balance: Option<String>,
+    pub initial_balance: Option<NearToken>,
     pub jsvm_account_id: Option<String>,
     pub network_id: Option<String>,
     pub node_url: String,
     pub key_store: KeyStore,
     pub master_account: Option<String>,
-    pub signer: Option<Signer>,
+    pub signer: Option<Arc<dyn Signer>>,
     pub transport: Option<Transport>,
     pub wallet_url: Option<String>,
 }
@@ -37,3 +38,26 @@ impl NearConfig {
         }
     }
 }
+
+impl PartialEq for NearConfig {
+    fn eq(&self, other: &Self) -> bool {
+        let same_signer = match (&self.signer, &other.signer) {
+            (Some(signer), Some(other_signer)) => Arc::ptr_eq(signer, other_signer),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_signer
+            && self.explorer_url == other.explorer_url
+            && self.headers == other.headers
+            && self.helper_url == other.helper_url
+            && self.initial_balance == other.initial_balance
+            && self.jsvm_account_id == other.jsvm_account_id
+            && self.network_id == other.network_id
+            && self.node_url == other.node_url
+            && self.key_store == other.key_store
+            && self.master_account == other.master_account
+            && self.transport == other.transport
+            && self.wallet_url == other.wallet_url
+    }
+}
@@ -4,10 +4,9 @@ use {
         account::Account,
         crypto::KeyType,
         key_pair::KeyPair,
-        key_store::KeyStore,
+        key_store::{KeyStore, KeyStoreError},
         primitives::{transaction::Transaction, types::AccountId},
         serialize::to_base64,
-        signer::Signer,
     },
     borsh::BorshSerialize,
     log::info,
@@ -59,10 +58,7 @@ impl WalletConnection {
             #[cfg(not(target_arch = "wasm32"))]
             None
         };
-        let key_store = match near.connection.signer.clone() {
-            Signer::InMemorySigner(signer) => signer.key_store,
-            _ => KeyStore::new(),
-        };
+        let key_store = config.key_store;
 
         Self {
             near,
@@ -118,7 +114,7 @@ impl WalletConnection {
         method_names: Option<Vec<String>>,
         success_url: Option<String>,
         failure_url: Option<String>,
-    ) {
+    ) -> Result<(), KeyStoreError> {
         let mut url = Url::parse(&format!(
             "{}{}",
             self.wallet_base_url, LOGIN_WALLET_URL_SUFFIX
@@ -137,7 +133,9 @@ impl WalletConnection {
             let network_id = self.network_id.clone();
             let temp_key = format!("{}{}", PENDING_ACCESS_KEY_PREFIX, public_key);
 
-            self.key_store.set_key(&network_id, &temp_key, key_pair);
+            self.key_store
+                .set_key(&network_id, &temp_key, key_pair)
+                .await?;
         }
         if let Some(method_names) = method_names {
             for method_name in method_names {
@@ -174,6 +172,8 @@ impl WalletConnection {
         {
             crate::browser::location().assign(&url);
         }
+
+        Ok(())
     }
 
     pub async fn request_sign_transactions(
@@ -211,17 +211,25 @@ impl WalletConnection {
         }
     }
 
-    fn _move_key_from_temp_to_permanent(&self, account_id: &str, public_key: &str) {
+    async fn _move_key_from_temp_to_permanent(
+        &self,
+        account_id: &str,
+        public_key: &str,
+    ) -> Result<(), KeyStoreError> {
         let temp_key = format!("{}{}", PENDING_ACCESS_KEY_PREFIX, public_key);
         let network_id = self.network_id.clone();
-        let key_pair = self.key_store.get_key(&network_id, &temp_key);
+        let key_pair = self.key_store.get_key(&network_id, &temp_key).await?;
         if let Some(key_pair) = key_pair {
-            self.key_store.set_key(&network_id, account_id, key_pair);
-            self.key_store.remove_key(&network_id, &temp_key);
+            self.key_store
+                .set_key(&network_id, account_id, key_pair)
+                .await?;
+            self.key_store.remove_key(&network_id, &temp_key).await?;
         }
+
+        Ok(())
     }
 
-    pub fn complete_sign_in_with_access_key(&mut self) {
+    pub async fn complete_sign_in_with_access_key(&mut self) -> Result<(), KeyStoreError> {
         #[cfg(all(target_arch = "wasm32", feature = "local_storage"))]
         {
             let storage = crate::browser::local_storage();
@@ -239,7 +247,8 @@ impl WalletConnection {
                     .set_item(&self.auth_data_key, &result)
                     .expect("fail to save data");
                 if let Some(public_key) = params.clone().get("public_key") {
-                    self._move_key_from_temp_to_permanent(&account_id, public_key);
+                    self._move_key_from_temp_to_permanent(&account_id, public_key)
+                        .await?;
                 }
                 self.auth_data = Some(auth_data);
                 let mut new_params = params.clone();
@@ -259,5 +268,7 @@ impl WalletConnection {
                 crate::browser::replace_url(parsed_url.as_str());
             }
         }
+
+        Ok(())
     }
 }
@@ -0,0 +1,9 @@
+mod nft_contract_metadata;
+mod non_fungible_token;
+mod token;
+
+pub use {
+    nft_contract_metadata::{NftContractMetadata, NFT_METADATA_SPEC},
+    non_fungible_token::NonFungibleToken,
+    token::{Token, TokenMetadata},
+};
@@ -0,0 +1,14 @@
+pub const NFT_METADATA_SPEC: &str = "nft-1.0.0";
+
+/// Contract metadata returned by `nft_metadata`, per NEP-177.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NftContractMetadata {
+    pub spec: String,
+    pub name: String,
+    pub symbol: String,
+    pub icon: Option<String>,
+    /// Prefix for relative `media` and `reference` of tokens.
+    pub base_uri: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<String>,
+}
@@ -0,0 +1,170 @@
+use {
+    super::{NftContractMetadata, Token},
+    crate::{
+        account::Account,
+        contract::{Contract, ContractError, ContractResult, ONE_YOCTO, U128},
+        primitives::types::{NearGas, NearToken},
+    },
+    serde_json::json,
+};
+
+const NFT_TRANSFER_GAS: NearGas = NearGas::from_tgas(10);
+/// Also covers `nft_on_transfer` of the receiver and `nft_resolve_transfer`.
+const NFT_TRANSFER_CALL_GAS: NearGas = NearGas::from_tgas(100);
+const NFT_APPROVE_GAS: NearGas = NearGas::from_tgas(10);
+/// Also covers `nft_on_approve` of the approved account.
+const NFT_APPROVE_CALL_GAS: NearGas = NearGas::from_tgas(100);
+const NFT_REVOKE_GAS: NearGas = NearGas::from_tgas(10);
+
+/// A NEP-171 non-fungible token contract called through an account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NonFungibleToken {
+    contract: Contract,
+}
+
+impl NonFungibleToken {
+    pub fn new(account: Account, contract_id: &str) -> Self {
+        Self {
+            contract: Contract::new(account, contract_id),
+        }
+    }
+
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    pub async fn nft_metadata(&self) -> Result<NftContractMetadata, ContractError> {
+        Ok(self.contract.view("nft_metadata", &json!({})).await?.value)
+    }
+
+    /// `None` when the token does not exist.
+    pub async fn nft_token(&self, token_id: &str) -> Result<Option<Token>, ContractError> {
+        Ok(self
+            .contract
+            .view("nft_token", &json!({ "token_id": token_id }))
+            .await?
+            .value)
+    }
+
+    /// Tokens from `from_index`, at most `limit` of them or a contract defined page size.
+    pub async fn nft_tokens(
+        &self,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Token>, ContractError> {
+        let args = json!({
+            "from_index": from_index.map(U128),
+            "limit": limit,
+        });
+
+        Ok(self.contract.view("nft_tokens", &args).await?.value)
+    }
+
+    pub async fn nft_tokens_for_owner(
+        &self,
+        account_id: &str,
+        from_index: Option<u128>,
+        limit: Option<u64>,
+    ) -> Result<Vec<Token>, ContractError> {
+        let args = json!({
+            "account_id": account_id,
+            "from_index": from_index.map(U128),
+            "limit": limit,
+        });
+
+        Ok(self
+            .contract
+            .view("nft_tokens_for_owner", &args)
+            .await?
+            .value)
+    }
+
+    pub async fn nft_supply_for_owner(&self, account_id: &str) -> Result<u128, ContractError> {
+        let result = self
+            .contract
+            .view::<_, U128>("nft_supply_for_owner", &json!({ "account_id": account_id }))
+            .await?;
+
+        Ok(result.value.into())
+    }
+
+    /// `approval_id` is required when the caller transfers as an approved account.
+    pub async fn nft_transfer(
+        &mut self,
+        receiver_id: &str,
+        token_id: &str,
+        approval_id: Option<u64>,
+        memo: Option<String>,
+    ) -> Result<ContractResult<()>, ContractError> {
+        let args = json!({
+            "receiver_id": receiver_id,
+            "token_id": token_id,
+            "approval_id": approval_id,
+            "memo": memo,
+        });
+
+        self.contract
+            .call("nft_transfer", &args, NFT_TRANSFER_GAS, ONE_YOCTO)
+            .await
+    }
+
+    /// Returns `true` when the token was transferred, `false` when the receiver returned it.
+    pub async fn nft_transfer_call(
+        &mut self,
+        receiver_id: &str,
+        token_id: &str,
+        approval_id: Option<u64>,
+        memo: Option<String>,
+        msg: &str,
+    ) -> Result<ContractResult<bool>, ContractError> {
+        let args = json!({
+            "receiver_id": receiver_id,
+            "token_id": token_id,
+            "approval_id": approval_id,
+            "memo": memo,
+            "msg": msg,
+        });
+
+        self.contract
+            .call("nft_transfer_call", &args, NFT_TRANSFER_CALL_GAS, ONE_YOCTO)
+            .await
+    }
+
+    /// The deposit pays for storing the approval, at least one yoctoNEAR. With `msg` the
+    /// contract calls `nft_on_approve` of `account_id` and returns its result.
+    pub async fn nft_approve(
+        &mut self,
+        token_id: &str,
+        account_id: &str,
+        msg: Option<String>,
+        deposit: impl Into<NearToken>,
+    ) -> Result<ContractResult<serde_json::Value>, ContractError> {
+        let gas = match msg.is_some() {
+            true => NFT_APPROVE_CALL_GAS,
+            false => NFT_APPROVE_GAS,
+        };
+        let deposit = deposit.into().max(ONE_YOCTO);
+        let args = json!({
+            "token_id": token_id,
+            "account_id": account_id,
+            "msg": msg,
+        });
+
+        self.contract.call("nft_approve", &args, gas, deposit).await
+    }
+
+    pub async fn nft_revoke(
+        &mut self,
+        token_id: &str,
+        account_id: &str,
+    ) -> Result<ContractResult<()>, ContractError> {
+        let args = json!({
+            "token_id": token_id,
+            "account_id": account_id,
+        });
+
+        self.contract
+            .call("nft_revoke", &args, NFT_REVOKE_GAS, ONE_YOCTO)
+            .await
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+/// A token returned by `nft_token`, per NEP-171.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Token {
+    pub token_id: String,
+    pub owner_id: String,
+    /// Set when the contract implements NEP-177.
+    pub metadata: Option<TokenMetadata>,
+    /// Approval ids by account, set when the contract implements NEP-178.
+    pub approved_account_ids: Option<HashMap<String, u64>>,
+}
+
+/// Metadata of a single token, per NEP-177. Timestamps are unix epoch milliseconds.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub media: Option<String>,
+    pub media_hash: Option<String>,
+    pub copies: Option<u64>,
+    pub issued_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub starts_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub extra: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_deserialize_token() {
+        let token: Token = serde_json::from_str(
+            r#"{
+                "token_id": "1",
+                "owner_id": "alice.testnet",
+                "metadata": { "title": "One", "copies": 1 },
+                "approved_account_ids": { "market.testnet": 3 }
+            }"#,
+        )
+        .unwrap();
+
+        let metadata = token.metadata.unwrap();
+        assert_eq!(metadata.title.as_deref(), Some("One"));
+        assert_eq!(metadata.copies, Some(1));
+        assert_eq!(metadata.media, None);
+        assert_eq!(
+            token.approved_account_ids.unwrap().get("market.testnet"),
+            Some(&3)
+        );
+
+        let token: Token =
+            serde_json::from_str(r#"{ "token_id": "2", "owner_id": "bob.testnet" }"#).unwrap();
+        assert_eq!(token.metadata, None);
+    }
+}
@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SeedPhraseError {
+    #[error("invalid seed phrase: {error_message}")]
+    InvalidSeedPhrase { error_message: String },
+    #[error("invalid derivation path '{path}': {error_message}")]
+    InvalidDerivationPath {
+        path: String,
+        error_message: String,
+    },
+}
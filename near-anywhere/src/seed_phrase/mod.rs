@@ -0,0 +1,13 @@
+mod errors;
+mod seed_phrase;
+mod slip10;
+
+pub use {
+    errors::SeedPhraseError,
+    seed_phrase::{normalize_seed_phrase, SeedPhrase},
+    slip10::{derive_ed25519_key, parse_derivation_path},
+};
+
+/// Derivation path used by the NEAR wallet, `397` is the SLIP-44 coin type of NEAR.
+pub const DEFAULT_KEY_DERIVATION_PATH: &str = "m/44'/397'/0'";
+pub const DEFAULT_SEED_PHRASE_WORD_COUNT: usize = 12;
@@ -0,0 +1,166 @@
+use {
+    super::{
+        derive_ed25519_key,
+        SeedPhraseError,
+        DEFAULT_KEY_DERIVATION_PATH,
+        DEFAULT_SEED_PHRASE_WORD_COUNT,
+    },
+    crate::{crypto::SecretKey, key_pair::KeyPair},
+    bip39::Mnemonic,
+    core::fmt::{Debug, Formatter},
+    zeroize::Zeroizing,
+};
+
+/// Lowercases the words and collapses the whitespace between them.
+pub fn normalize_seed_phrase(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// A BIP-39 mnemonic, compatible with the seed phrases of the NEAR wallet.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SeedPhrase {
+    mnemonic: Mnemonic,
+}
+
+impl SeedPhrase {
+    pub fn generate(word_count: usize) -> Result<Self, SeedPhraseError> {
+        let mnemonic =
+            Mnemonic::generate(word_count).map_err(|err| SeedPhraseError::InvalidSeedPhrase {
+                error_message: err.to_string(),
+            })?;
+
+        Ok(Self { mnemonic })
+    }
+
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, SeedPhraseError> {
+        let mnemonic =
+            Mnemonic::from_entropy(entropy).map_err(|err| SeedPhraseError::InvalidSeedPhrase {
+                error_message: err.to_string(),
+            })?;
+
+        Ok(Self { mnemonic })
+    }
+
+    pub fn parse(phrase: &str) -> Result<Self, SeedPhraseError> {
+        let mnemonic = Mnemonic::parse_normalized(&normalize_seed_phrase(phrase)).map_err(
+            |err| SeedPhraseError::InvalidSeedPhrase {
+                error_message: err.to_string(),
+            },
+        )?;
+
+        Ok(Self { mnemonic })
+    }
+
+    pub fn phrase(&self) -> Zeroizing<String> {
+        Zeroizing::new(self.mnemonic.to_string())
+    }
+
+    pub fn word_count(&self) -> usize {
+        self.mnemonic.word_count()
+    }
+
+    pub fn to_seed(&self, passphrase: &str) -> Zeroizing<[u8; 64]> {
+        Zeroizing::new(self.mnemonic.to_seed(passphrase))
+    }
+
+    /// Derives the ed25519 secret key at the SLIP-10 path, the NEAR wallet uses no passphrase.
+    pub fn derive_secret_key(&self, path: &str) -> Result<SecretKey, SeedPhraseError> {
+        let key = derive_ed25519_key(self.to_seed("").as_ref(), path)?;
+
+        Ok(SecretKey::ed25519_from_seed(&key))
+    }
+
+    pub fn derive_key_pair(&self, path: &str) -> Result<KeyPair, SeedPhraseError> {
+        Ok(KeyPair::from(self.derive_secret_key(path)?))
+    }
+
+    /// Key pair at the default `m/44'/397'/0'` path.
+    pub fn key_pair(&self) -> KeyPair {
+        self.derive_key_pair(DEFAULT_KEY_DERIVATION_PATH)
+            .expect("default derivation path is valid")
+    }
+}
+
+impl Debug for SeedPhrase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "SeedPhrase(<{} words redacted>)", self.word_count())
+    }
+}
+
+impl Default for SeedPhrase {
+    fn default() -> Self {
+        Self::generate(DEFAULT_SEED_PHRASE_WORD_COUNT).expect("default word count is valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_bip39_vector() {
+        let seed_phrase = SeedPhrase::from_entropy(&[0; 16]).unwrap();
+
+        assert_eq!(
+            seed_phrase.phrase().as_str(),
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+        assert_eq!(
+            seed_phrase
+                .to_seed("TREZOR")
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>(),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+    }
+
+    #[test]
+    pub fn test_near_seed_phrase_vector() {
+        let key_pair = SeedPhrase::parse(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap()
+        .key_pair();
+
+        assert_eq!(
+            key_pair.public_key().to_string(),
+            "ed25519:6j4b6zUaty6fD1awqcGCCU9JYGCWYUgdJhQrzfZhqE25"
+        );
+        assert_eq!(
+            key_pair.to_secret_string().as_str(),
+            "ed25519:F1kPR175szkGxEL52A9H6Z5ocS2BtaipprK2Hiob9DjGzHTkScrBb1yt44baXPZ3LxyHcsTPdBjHmn6zx147txH"
+        );
+    }
+
+    #[test]
+    pub fn test_generate_and_parse_seed_phrase() {
+        let seed_phrase = SeedPhrase::default();
+        let parsed = SeedPhrase::parse(&seed_phrase.phrase()).unwrap();
+
+        assert_eq!(seed_phrase.word_count(), DEFAULT_SEED_PHRASE_WORD_COUNT);
+        assert_eq!(
+            seed_phrase.key_pair().public_key(),
+            parsed.key_pair().public_key()
+        );
+        assert_ne!(
+            seed_phrase.key_pair().public_key(),
+            parsed.derive_key_pair("m/44'/397'/1'").unwrap().public_key()
+        );
+    }
+
+    #[test]
+    pub fn test_normalize_seed_phrase() {
+        assert_eq!(normalize_seed_phrase("  Abc  def\n  ghi "), "abc def ghi");
+
+        let phrase = SeedPhrase::from_entropy(&[1; 16]).unwrap().phrase();
+        let messy_phrase = format!("  {}  ", phrase.to_uppercase().replace(' ', "\n  "));
+        assert_eq!(SeedPhrase::parse(&messy_phrase).unwrap().phrase(), phrase);
+        assert!(!format!("{:?}", SeedPhrase::parse(&phrase).unwrap()).contains(phrase.as_str()));
+        assert!(SeedPhrase::parse("not a valid seed phrase").is_err());
+    }
+}
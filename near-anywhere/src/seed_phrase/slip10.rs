@@ -0,0 +1,123 @@
+use {
+    super::SeedPhraseError,
+    hmac::{Hmac, Mac},
+    sha2::Sha512,
+    zeroize::{Zeroize, Zeroizing},
+};
+
+const ED25519_CURVE_SEED: &[u8] = b"ed25519 seed";
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// Parses a path like `m/44'/397'/0'`, ed25519 only supports hardened indexes so `'` is optional.
+pub fn parse_derivation_path(path: &str) -> Result<Vec<u32>, SeedPhraseError> {
+    let invalid_path = |error_message: &str| SeedPhraseError::InvalidDerivationPath {
+        path: path.to_string(),
+        error_message: error_message.to_string(),
+    };
+    let mut segments = path.split('/');
+
+    if segments.next() != Some("m") {
+        return Err(invalid_path("path must start with 'm'"));
+    }
+
+    segments
+        .map(|segment| {
+            let index = segment.trim_end_matches('\'').parse::<u32>().map_err(|_| {
+                invalid_path(&format!("invalid segment '{}'", segment))
+            })?;
+
+            match index < HARDENED_OFFSET {
+                true => Ok(index),
+                false => Err(invalid_path(&format!("index {} is too large", index))),
+            }
+        })
+        .collect()
+}
+
+type ExtendedKey = (Zeroizing<[u8; 32]>, Zeroizing<[u8; 32]>);
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> ExtendedKey {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    let mut result = mac.finalize().into_bytes();
+    let mut key = Zeroizing::new([0u8; 32]);
+    let mut chain_code = Zeroizing::new([0u8; 32]);
+    key.copy_from_slice(&result[..32]);
+    chain_code.copy_from_slice(&result[32..]);
+    result[..].zeroize();
+
+    (key, chain_code)
+}
+
+/// Derives an ed25519 private key from a seed following SLIP-10.
+pub fn derive_ed25519_key(
+    seed: &[u8],
+    path: &str,
+) -> Result<Zeroizing<[u8; 32]>, SeedPhraseError> {
+    let indexes = parse_derivation_path(path)?;
+    let (mut key, mut chain_code) = hmac_sha512(ED25519_CURVE_SEED, seed);
+
+    for index in indexes {
+        let mut data = Zeroizing::new(Vec::with_capacity(37));
+        data.push(0u8);
+        data.extend_from_slice(key.as_ref());
+        data.extend_from_slice(&(index | HARDENED_OFFSET).to_be_bytes());
+        (key, chain_code) = hmac_sha512(chain_code.as_ref(), &data);
+    }
+
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(data: &str) -> Vec<u8> {
+        (0..data.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&data[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    pub fn test_slip10_ed25519_vectors() {
+        let vectors = [
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "m",
+                "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "m/0'",
+                "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f",
+                "m/0'/1'/2'/2'/1000000000'",
+                "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+            ),
+            (
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "m",
+                "171cb88b1b3c1db25add599712e36245d75bc65a1a5c9e18d76f9f2b1eab4012",
+            ),
+        ];
+
+        for (seed, path, key) in vectors {
+            assert_eq!(
+                derive_ed25519_key(&from_hex(seed), path).unwrap().to_vec(),
+                from_hex(key)
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_parse_derivation_path() {
+        assert_eq!(parse_derivation_path("m/44'/397'/0'").unwrap(), vec![44, 397, 0]);
+        assert_eq!(parse_derivation_path("m/44/397/1").unwrap(), vec![44, 397, 1]);
+        assert!(parse_derivation_path("44'/397'").is_err());
+        assert!(parse_derivation_path("m/a'").is_err());
+        assert!(parse_derivation_path("m/2147483648'").is_err());
+    }
+}
@@ -0,0 +1,36 @@
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SignerError {
+    #[error("no key found for account '{account_id}' on network '{network_id}'")]
+    KeyNotFound {
+        account_id: String,
+        network_id: String,
+    },
+    #[error("key '{public_key}' not found for account '{account_id}'")]
+    PublicKeyNotFound {
+        account_id: String,
+        public_key: String,
+    },
+    #[error("remote signer error: {error_message}")]
+    Remote { error_message: String },
+    #[error("signer does not support '{operation}'")]
+    Unsupported { operation: String },
+    #[error("{error_message}")]
+    KeyStore { error_message: String },
+}
+
+impl From<crate::key_store::KeyStoreError> for SignerError {
+    fn from(error: crate::key_store::KeyStoreError) -> Self {
+        Self::KeyStore {
+            error_message: error.to_string(),
+        }
+    }
+}
+
+impl From<SignerError> for crate::client::RpcError {
+    fn from(error: SignerError) -> Self {
+        Self {
+            code: 0,
+            message: error.to_string(),
+        }
+    }
+}
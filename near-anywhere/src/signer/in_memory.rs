@@ -1,11 +1,12 @@
 use {
+    super::{Signer, SignerError},
     crate::{
         crypto::{KeyType, PublicKey, Signature},
         key_pair::KeyPair,
-        key_store::KeyStore,
+        key_store::{InMemoryKeyStore, KeyStore},
     },
+    async_trait::async_trait,
     sha2::Digest,
-    std::io::{Error, ErrorKind},
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -17,45 +18,120 @@ impl InMemorySigner {
     pub fn new(key_store: KeyStore) -> Self {
         Self { key_store }
     }
+
+    pub fn from_key_pair(network_id: &str, account_id: &str, keypair: KeyPair) -> Self {
+        let key_store = InMemoryKeyStore::new();
+
+        key_store.set_key(network_id, account_id, keypair);
+
+        Self {
+            key_store: KeyStore::InMemoryStorage(key_store),
+        }
+    }
+
+    fn key_not_found(account_id: &str, network_id: &str) -> SignerError {
+        SignerError::KeyNotFound {
+            account_id: account_id.to_string(),
+            network_id: network_id.to_string(),
+        }
+    }
 }
 
-impl InMemorySigner {
-    pub fn create_key(&self, account_id: &str, network_id: &str) -> PublicKey {
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for InMemorySigner {
+    /// Existing keys of the account are kept, the new key is only its default key when the
+    /// account has no other key.
+    async fn create_key(
+        &self,
+        account_id: &str,
+        network_id: &str,
+    ) -> Result<PublicKey, SignerError> {
         let key_pair = KeyPair::from_random(KeyType::ED25519);
         let public_key = key_pair.public_key();
-        self.key_store.set_key(network_id, account_id, key_pair);
+        self.key_store
+            .add_key(network_id, account_id, key_pair)
+            .await?;
 
-        public_key
+        Ok(public_key)
     }
 
-    pub fn from_key_pair(network_id: &str, account_id: &str, keypair: KeyPair) -> Self {
-        let key_store = KeyStore::in_memory_key_store();
+    async fn get_public_key(
+        &self,
+        account_id: &str,
+        network_id: &str,
+    ) -> Result<PublicKey, SignerError> {
+        self.key_store
+            .get_public_key(network_id, account_id)
+            .await?
+            .ok_or_else(|| Self::key_not_found(account_id, network_id))
+    }
 
-        key_store.set_key(network_id, account_id, keypair);
+    async fn get_public_keys(
+        &self,
+        account_id: &str,
+        network_id: &str,
+    ) -> Result<Vec<PublicKey>, SignerError> {
+        Ok(self
+            .key_store
+            .get_public_keys(network_id, account_id)
+            .await?)
+    }
 
-        Self { key_store }
+    async fn sign_message(
+        &self,
+        message: &[u8],
+        account_id: &str,
+        network_id: &str,
+    ) -> Result<Signature, SignerError> {
+        let hash = sha2::Sha256::digest(message).into();
+
+        // Signing goes through the key store, so keys that cannot be exported can sign too.
+        self.key_store
+            .sign(network_id, account_id, &hash)
+            .await?
+            .ok_or_else(|| Self::key_not_found(account_id, network_id))
     }
 
-    pub fn get_public_key(&self, account_id: &str, network_id: &str) -> Result<PublicKey, Error> {
-        let key_pair = self.key_store.get_key(network_id, account_id);
+    async fn sign_message_with_public_key(
+        &self,
+        message: &[u8],
+        account_id: &str,
+        network_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<Signature, SignerError> {
+        let hash = sha2::Sha256::digest(message).into();
 
-        match key_pair {
-            Some(key_pair) => Ok(key_pair.public_key()),
-            None => Err(Error::new(
-                ErrorKind::NotFound,
-                "No key found for this account",
-            )),
-        }
+        self.key_store
+            .sign_with_public_key(network_id, account_id, public_key, &hash)
+            .await?
+            .ok_or_else(|| SignerError::PublicKeyNotFound {
+                account_id: account_id.to_string(),
+                public_key: public_key.to_string(),
+            })
     }
 
-    pub fn sign_message(&self, message: &[u8], account_id: &str, network_id: &str) -> Signature {
-        let hash = sha2::Sha256::digest(message);
-        let hash = hash.as_slice();
-        let key_pair = self
+    async fn set_default_key(
+        &self,
+        account_id: &str,
+        network_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<(), SignerError> {
+        Ok(self
             .key_store
-            .get_key(network_id, account_id)
-            .expect("keypair not found");
+            .set_default_key(network_id, account_id, public_key)
+            .await?)
+    }
 
-        key_pair.sign(hash)
+    async fn remove_key(
+        &self,
+        account_id: &str,
+        network_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<(), SignerError> {
+        Ok(self
+            .key_store
+            .remove_key_by_public_key(network_id, account_id, public_key)
+            .await?)
     }
 }
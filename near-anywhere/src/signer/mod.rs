@@ -1,4 +1,26 @@
+mod errors;
 mod in_memory;
+mod nep413;
+mod remote;
 mod signer;
 
-pub use {in_memory::InMemorySigner, signer::Signer};
+pub use {
+    errors::SignerError,
+    in_memory::InMemorySigner,
+    nep413::{
+        verify_signed_message,
+        SignMessageParams,
+        SignedMessage,
+        NEP413_SIGN_MESSAGE_PREFIX,
+    },
+    remote::{
+        RemotePublicKeyRequest,
+        RemotePublicKeyResponse,
+        RemoteSignRequest,
+        RemoteSignResponse,
+        RemoteSigner,
+        REMOTE_SIGNER_PUBLIC_KEY_PATH,
+        REMOTE_SIGNER_SIGN_PATH,
+    },
+    signer::{MaybeSendSync, Signer},
+};
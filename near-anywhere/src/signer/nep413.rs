@@ -0,0 +1,194 @@
+use {
+    super::{Signer, SignerError},
+    crate::{
+        client::RpcError,
+        crypto::{PublicKey, Signature},
+        hash::CryptoHash,
+        key_pair::KeyPair,
+        primitives::{access_key::AccessKeyPermissionView, near::NearRpcUser, types::AccountId},
+        serialize::{from_base64, to_base64},
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+};
+
+/// Prefix tag of NEP-413 payloads, `2^31 + 413`, so a signed message can never be a valid transaction.
+pub const NEP413_SIGN_MESSAGE_PREFIX: u32 = (1u32 << 31) + 413;
+
+/// Message to be signed off-chain as specified by NEP-413.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SignMessageParams {
+    pub message: String,
+    pub nonce: [u8; 32],
+    pub recipient: String,
+    pub callback_url: Option<String>,
+}
+
+/// Standard output of a NEP-413 signature, the signature is base64 encoded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedMessage {
+    pub account_id: AccountId,
+    pub public_key: PublicKey,
+    pub signature: String,
+}
+
+impl SignMessageParams {
+    pub fn new(message: &str, recipient: &str, nonce: [u8; 32]) -> Self {
+        Self {
+            message: message.to_string(),
+            nonce,
+            recipient: recipient.to_string(),
+            callback_url: None,
+        }
+    }
+
+    pub fn with_callback_url(mut self, callback_url: &str) -> Self {
+        self.callback_url = Some(callback_url.to_string());
+        self
+    }
+
+    /// Borsh serialized prefix tag followed by the borsh serialized payload.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = NEP413_SIGN_MESSAGE_PREFIX
+            .try_to_vec()
+            .expect("fail to serialize the prefix");
+        bytes.extend(self.try_to_vec().expect("fail to serialize the payload"));
+
+        bytes
+    }
+
+    pub fn hash(&self) -> CryptoHash {
+        CryptoHash::hash_bytes(&self.to_bytes())
+    }
+
+    pub fn sign(&self, key_pair: &KeyPair, account_id: &AccountId) -> SignedMessage {
+        let signature = key_pair.sign_prehashed(&self.hash().0);
+
+        SignedMessage::new(account_id.clone(), key_pair.public_key(), &signature)
+    }
+
+    pub async fn sign_with_signer(
+        &self,
+        signer: &dyn Signer,
+        account_id: &AccountId,
+        network_id: &str,
+    ) -> Result<SignedMessage, SignerError> {
+        let public_key = signer.get_public_key(account_id, network_id).await?;
+        // The signer hashes the message with sha256 before signing it.
+        let signature = signer
+            .sign_message(&self.to_bytes(), account_id, network_id)
+            .await?;
+
+        Ok(SignedMessage::new(account_id.clone(), public_key, &signature))
+    }
+}
+
+impl SignedMessage {
+    pub fn new(account_id: AccountId, public_key: PublicKey, signature: &Signature) -> Self {
+        let signature = match signature {
+            Signature::ED25519(signature) => to_base64(signature.to_bytes()),
+            Signature::SECP256K1(signature) => to_base64(signature.0),
+        };
+
+        Self {
+            account_id,
+            public_key,
+            signature,
+        }
+    }
+
+    pub fn signature(&self) -> Option<Signature> {
+        let data = from_base64(&self.signature).ok()?;
+
+        Signature::from_parts(self.public_key.key_type(), &data).ok()
+    }
+
+    /// Checks the signature against the given params, without looking at the chain.
+    pub fn verify(&self, params: &SignMessageParams) -> bool {
+        match self.signature() {
+            Some(signature) => signature.verify_prehashed(&params.hash().0, &self.public_key),
+            None => false,
+        }
+    }
+}
+
+/// Checks the signature and optionally that the signing key is a full access key of the account.
+pub async fn verify_signed_message(
+    near_rpc_user: &NearRpcUser,
+    signed_message: &SignedMessage,
+    params: &SignMessageParams,
+    require_full_access_key: bool,
+) -> Result<bool, RpcError> {
+    if !signed_message.verify(params) {
+        return Ok(false);
+    }
+    if !require_full_access_key {
+        return Ok(true);
+    }
+
+    let access_keys = near_rpc_user
+        .view_access_key_list(&signed_message.account_id)
+        .await?;
+    let is_full_access_key = access_keys.keys.iter().any(|item| {
+        item.public_key == signed_message.public_key
+            && item.access_key.permission == AccessKeyPermissionView::FullAccess
+    });
+
+    Ok(is_full_access_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_pair() -> KeyPair {
+        KeyPair::from_secret_key("ed25519:2LRHYvi3uHHsADkF8HFRyZXJX3BG7HuQpuHw1cWcYSrivxNo5y76vkPA4ezKixS3jQ7e2zCfi4zfXbNAP72j2Ntk").expect("Failed to create key pair")
+    }
+
+    #[test]
+    pub fn test_payload_prefix() {
+        let params = SignMessageParams::new("hello", "app.near", [7; 32]);
+        let bytes = params.to_bytes();
+
+        assert_eq!(&bytes[..4], &[157, 1, 0, 128]);
+        assert_eq!(
+            SignMessageParams::try_from_slice(&bytes[4..]).unwrap(),
+            params
+        );
+    }
+
+    #[test]
+    pub fn test_sign_and_verify() {
+        let account_id: AccountId = "alice.near".parse().unwrap();
+        let params =
+            SignMessageParams::new("hello", "app.near", [1; 32]).with_callback_url("https://app");
+        let signed_message = params.sign(&key_pair(), &account_id);
+
+        assert!(signed_message.verify(&params));
+
+        let json = serde_json::to_value(&signed_message).unwrap();
+        assert_eq!(json["accountId"], "alice.near");
+        assert_eq!(
+            json["publicKey"],
+            "ed25519:G9upgDmY9DPYvkxZrR52Foh7g351TKee1E4BJdCLfZaU"
+        );
+
+        let other_params = SignMessageParams::new("hello", "evil.near", [1; 32]);
+        assert!(!signed_message.verify(&other_params));
+    }
+
+    #[test]
+    pub fn test_known_signature() {
+        let account_id: AccountId = "alice.near".parse().unwrap();
+        let nonce = core::array::from_fn(|index| index as u8);
+        let params = SignMessageParams::new("Hello NEAR!", "myapp.com", nonce)
+            .with_callback_url("http://127.0.0.1:8000/login/");
+
+        // Signed outside of this crate, with Python `cryptography` over the sha256 of the payload.
+        assert_eq!(
+            params.sign(&key_pair(), &account_id).signature,
+            "e8qkQPO/jWX10gM0JZj2kPYplClMN6l/VRZhvJMlive/EfABan5agkQEAF/SBrswb9Sj0MtZ8fEJEmvuq8BJBg=="
+        );
+    }
+}
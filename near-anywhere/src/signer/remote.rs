@@ -0,0 +1,162 @@
+use {
+    super::{Signer, SignerError},
+    crate::{
+        crypto::{PublicKey, Signature},
+        serialize::to_base64,
+    },
+    async_trait::async_trait,
+    core::fmt::{Debug, Formatter},
+    serde::{de::DeserializeOwned, Serialize},
+};
+
+pub const REMOTE_SIGNER_SIGN_PATH: &str = "/sign";
+pub const REMOTE_SIGNER_PUBLIC_KEY_PATH: &str = "/public_key";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RemotePublicKeyRequest {
+    pub account_id: String,
+    pub network_id: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RemotePublicKeyResponse {
+    pub public_key: PublicKey,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RemoteSignRequest {
+    pub account_id: String,
+    pub network_id: String,
+    /// Base64 encoded message, usually a borsh serialized transaction.
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RemoteSignResponse {
+    pub signature: Signature,
+}
+
+/// A signer that delegates signing to a signing service over HTTP.
+///
+/// Requests are authenticated with a bearer token.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    auth_token: String,
+}
+
+/// The auth token is never printed.
+impl Debug for RemoteSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("RemoteSigner")
+            .field("url", &self.url)
+            .field("auth_token", &"<redacted>")
+            .finish()
+    }
+}
+
+impl RemoteSigner {
+    pub fn new(url: &str, auth_token: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.trim_end_matches('/').to_string(),
+            auth_token: auth_token.to_string(),
+        }
+    }
+
+    async fn post<Request: Serialize, Response: DeserializeOwned>(
+        &self,
+        path: &str,
+        request: &Request,
+    ) -> Result<Response, SignerError> {
+        let response = self
+            .client
+            .post(format!("{}{}", self.url, path))
+            .bearer_auth(&self.auth_token)
+            .json(request)
+            .send()
+            .await
+            .map_err(|err| SignerError::Remote {
+                error_message: err.to_string(),
+            })?;
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_message = response.text().await.unwrap_or_default();
+
+            return Err(SignerError::Remote {
+                error_message: format!("{}: {}", status, error_message),
+            });
+        }
+
+        response.json().await.map_err(|err| SignerError::Remote {
+            error_message: err.to_string(),
+        })
+    }
+}
+
+impl PartialEq for RemoteSigner {
+    fn eq(&self, other: &Self) -> bool {
+        (&self.url, &self.auth_token) == (&other.url, &other.auth_token)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for RemoteSigner {
+    async fn create_key(
+        &self,
+        _account_id: &str,
+        _network_id: &str,
+    ) -> Result<PublicKey, SignerError> {
+        Err(SignerError::Unsupported {
+            operation: "create_key".to_string(),
+        })
+    }
+
+    async fn get_public_key(
+        &self,
+        account_id: &str,
+        network_id: &str,
+    ) -> Result<PublicKey, SignerError> {
+        let request = RemotePublicKeyRequest {
+            account_id: account_id.to_string(),
+            network_id: network_id.to_string(),
+        };
+        let response: RemotePublicKeyResponse =
+            self.post(REMOTE_SIGNER_PUBLIC_KEY_PATH, &request).await?;
+
+        Ok(response.public_key)
+    }
+
+    async fn sign_message(
+        &self,
+        message: &[u8],
+        account_id: &str,
+        network_id: &str,
+    ) -> Result<Signature, SignerError> {
+        let request = RemoteSignRequest {
+            account_id: account_id.to_string(),
+            network_id: network_id.to_string(),
+            message: to_base64(message),
+        };
+        let response: RemoteSignResponse = self.post(REMOTE_SIGNER_SIGN_PATH, &request).await?;
+
+        Ok(response.signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_debug_redacts_auth_token() {
+        let signer = RemoteSigner::new("https://signer.example.com/", "secret-token");
+        let debug = format!("{:?}", signer);
+
+        assert!(debug.contains("https://signer.example.com"));
+        assert!(!debug.contains("secret-token"));
+    }
+}
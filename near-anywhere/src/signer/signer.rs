@@ -1,37 +1,92 @@
 use {
-    super::InMemorySigner,
-    crate::{
-        crypto::{PublicKey, Signature},
-        key_store::KeyStore,
-    },
-    std::io::Error,
+    super::SignerError,
+    crate::crypto::{PublicKey, Signature},
+    async_trait::async_trait,
+    core::fmt::Debug,
 };
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum Signer {
-    InMemorySigner(InMemorySigner),
-}
+/// Signers are shared across threads on native targets, browser signers may hold `!Send` handles.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + Sync> MaybeSendSync for T {}
+
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSendSync {}
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSendSync for T {}
+
+/// Signs messages on behalf of accounts, implementations may keep the keys anywhere.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Signer: Debug + MaybeSendSync {
+    /// Creates a new key for the account and returns its public key.
+    async fn create_key(&self, account_id: &str, network_id: &str)
+        -> Result<PublicKey, SignerError>;
+
+    async fn get_public_key(
+        &self,
+        account_id: &str,
+        network_id: &str,
+    ) -> Result<PublicKey, SignerError>;
 
-impl Signer {
-    pub fn new_in_memory_signer(key_store: KeyStore) -> Self {
-        Signer::InMemorySigner(InMemorySigner::new(key_store))
+    /// Public keys of the account, the default key first.
+    async fn get_public_keys(
+        &self,
+        account_id: &str,
+        network_id: &str,
+    ) -> Result<Vec<PublicKey>, SignerError> {
+        Ok(vec![self.get_public_key(account_id, network_id).await?])
     }
 
-    pub fn create_key(&self, account_id: &str, network_id: &str) -> PublicKey {
-        match self {
-            Signer::InMemorySigner(signer) => signer.create_key(account_id, network_id),
+    /// Signs the sha256 hash of the message.
+    async fn sign_message(
+        &self,
+        message: &[u8],
+        account_id: &str,
+        network_id: &str,
+    ) -> Result<Signature, SignerError>;
+
+    /// Signs the sha256 hash of the message with the key of `public_key`.
+    ///
+    /// Signers holding a single key only sign with their default key.
+    async fn sign_message_with_public_key(
+        &self,
+        message: &[u8],
+        account_id: &str,
+        network_id: &str,
+        public_key: &PublicKey,
+    ) -> Result<Signature, SignerError> {
+        match &self.get_public_key(account_id, network_id).await? == public_key {
+            true => self.sign_message(message, account_id, network_id).await,
+            false => Err(SignerError::PublicKeyNotFound {
+                account_id: account_id.to_string(),
+                public_key: public_key.to_string(),
+            }),
         }
     }
 
-    pub fn get_public_key(&self, account_id: &str, network_id: &str) -> Result<PublicKey, Error> {
-        match self {
-            Signer::InMemorySigner(signer) => signer.get_public_key(account_id, network_id),
-        }
+    /// Makes the key of `public_key` the default key of the account.
+    async fn set_default_key(
+        &self,
+        _account_id: &str,
+        _network_id: &str,
+        _public_key: &PublicKey,
+    ) -> Result<(), SignerError> {
+        Err(SignerError::Unsupported {
+            operation: "set_default_key".to_string(),
+        })
     }
 
-    pub fn sign_message(&self, message: &[u8], account_id: &str, network_id: &str) -> Signature {
-        match self {
-            Signer::InMemorySigner(signer) => signer.sign_message(message, account_id, network_id),
-        }
+    /// Removes the key of `public_key` from the account.
+    async fn remove_key(
+        &self,
+        _account_id: &str,
+        _network_id: &str,
+        _public_key: &PublicKey,
+    ) -> Result<(), SignerError> {
+        Err(SignerError::Unsupported {
+            operation: "remove_key".to_string(),
+        })
     }
 }
@@ -0,0 +1,9 @@
+mod reward_fee_fraction;
+mod staking_pool;
+mod withdrawal_estimate;
+
+pub use {
+    reward_fee_fraction::RewardFeeFraction,
+    staking_pool::StakingPool,
+    withdrawal_estimate::{WithdrawalEstimate, NUM_EPOCHS_TO_UNLOCK},
+};
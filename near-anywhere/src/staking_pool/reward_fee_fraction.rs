@@ -0,0 +1,44 @@
+use crate::primitives::types::NearToken;
+
+/// Share of staking rewards the pool owner keeps, returned by `get_reward_fee_fraction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RewardFeeFraction {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+impl RewardFeeFraction {
+    pub fn as_percentage(&self) -> f64 {
+        match self.denominator {
+            0 => 0.0,
+            _ => self.numerator as f64 * 100.0 / self.denominator as f64,
+        }
+    }
+
+    /// Part of `reward` taken as fee.
+    pub fn fee(&self, reward: NearToken) -> NearToken {
+        reward
+            .checked_mul(self.numerator as u128)
+            .and_then(|amount| amount.checked_div(self.denominator as u128))
+            .unwrap_or(NearToken::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_reward_fee_fraction() {
+        let fraction = RewardFeeFraction {
+            numerator: 10,
+            denominator: 100,
+        };
+
+        assert_eq!(fraction.as_percentage(), 10.0);
+        assert_eq!(
+            fraction.fee(NearToken::from_near(5)),
+            NearToken::from_millinear(500)
+        );
+    }
+}
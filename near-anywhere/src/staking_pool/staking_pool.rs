@@ -0,0 +1,147 @@
+use {
+    super::{RewardFeeFraction, WithdrawalEstimate},
+    crate::{
+        account::Account,
+        contract::{Contract, ContractError, ContractResult},
+        primitives::types::{EpochHeight, NearGas, NearToken},
+    },
+    serde_json::json,
+};
+
+/// Staking pool methods may also run the internal `ping` distributing rewards.
+const STAKING_POOL_GAS: NearGas = NearGas::from_tgas(125);
+
+/// A staking pool contract called through an account, for delegating stake to a validator.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakingPool {
+    contract: Contract,
+}
+
+impl StakingPool {
+    pub fn new(account: Account, contract_id: &str) -> Self {
+        Self {
+            contract: Contract::new(account, contract_id),
+        }
+    }
+
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    pub async fn deposit_and_stake(
+        &mut self,
+        amount: impl Into<NearToken>,
+    ) -> Result<ContractResult<()>, ContractError> {
+        self.contract
+            .call("deposit_and_stake", &json!({}), STAKING_POOL_GAS, amount)
+            .await
+    }
+
+    /// Unstaked funds are locked for `NUM_EPOCHS_TO_UNLOCK` epochs, see `estimate_withdrawal`.
+    pub async fn unstake(
+        &mut self,
+        amount: impl Into<NearToken>,
+    ) -> Result<ContractResult<()>, ContractError> {
+        let args = json!({ "amount": amount.into() });
+
+        self.call("unstake", &args).await
+    }
+
+    pub async fn unstake_all(&mut self) -> Result<ContractResult<()>, ContractError> {
+        self.call("unstake_all", &json!({})).await
+    }
+
+    pub async fn withdraw(
+        &mut self,
+        amount: impl Into<NearToken>,
+    ) -> Result<ContractResult<()>, ContractError> {
+        let args = json!({ "amount": amount.into() });
+
+        self.call("withdraw", &args).await
+    }
+
+    pub async fn withdraw_all(&mut self) -> Result<ContractResult<()>, ContractError> {
+        self.call("withdraw_all", &json!({})).await
+    }
+
+    pub async fn get_account_staked_balance(
+        &self,
+        account_id: &str,
+    ) -> Result<NearToken, ContractError> {
+        self.view_balance("get_account_staked_balance", account_id)
+            .await
+    }
+
+    pub async fn get_account_unstaked_balance(
+        &self,
+        account_id: &str,
+    ) -> Result<NearToken, ContractError> {
+        self.view_balance("get_account_unstaked_balance", account_id)
+            .await
+    }
+
+    pub async fn is_account_unstaked_balance_available(
+        &self,
+        account_id: &str,
+    ) -> Result<bool, ContractError> {
+        Ok(self
+            .contract
+            .view(
+                "is_account_unstaked_balance_available",
+                &json!({ "account_id": account_id }),
+            )
+            .await?
+            .value)
+    }
+
+    pub async fn get_reward_fee_fraction(&self) -> Result<RewardFeeFraction, ContractError> {
+        Ok(self
+            .contract
+            .view("get_reward_fee_fraction", &json!({}))
+            .await?
+            .value)
+    }
+
+    /// Estimates when the unstaked balance of `account_id` can be withdrawn. The pool does not
+    /// expose when funds were unstaked, pass the epoch of the last `unstake` when known.
+    pub async fn estimate_withdrawal(
+        &self,
+        account_id: &str,
+        unstaked_epoch_height: Option<EpochHeight>,
+    ) -> Result<WithdrawalEstimate, ContractError> {
+        let unstaked_balance = self.get_account_unstaked_balance(account_id).await?;
+        let is_available = self
+            .is_account_unstaked_balance_available(account_id)
+            .await?;
+        let epoch_info = self.contract.account().get_epoch_info().await?;
+
+        Ok(WithdrawalEstimate::new(
+            unstaked_balance,
+            is_available,
+            unstaked_epoch_height,
+            &epoch_info,
+        ))
+    }
+
+    async fn call(
+        &mut self,
+        method_name: &str,
+        args: &serde_json::Value,
+    ) -> Result<ContractResult<()>, ContractError> {
+        self.contract
+            .call(method_name, args, STAKING_POOL_GAS, NearToken::ZERO)
+            .await
+    }
+
+    async fn view_balance(
+        &self,
+        method_name: &str,
+        account_id: &str,
+    ) -> Result<NearToken, ContractError> {
+        Ok(self
+            .contract
+            .view(method_name, &json!({ "account_id": account_id }))
+            .await?
+            .value)
+    }
+}
@@ -0,0 +1,84 @@
+use {
+    crate::{
+        account::EpochInfo,
+        primitives::types::{BlockHeightDelta, EpochHeight, NearToken},
+    },
+    core::time::Duration,
+};
+
+/// Epochs staking pools lock unstaked funds for.
+pub const NUM_EPOCHS_TO_UNLOCK: EpochHeight = 4;
+
+/// When the unstaked balance of an account in a staking pool can be withdrawn.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WithdrawalEstimate {
+    pub unstaked_balance: NearToken,
+    pub is_available: bool,
+    /// Epoch from which the balance can be withdrawn.
+    pub epoch_height: EpochHeight,
+    pub remaining_blocks: BlockHeightDelta,
+    pub remaining_time: Duration,
+}
+
+impl WithdrawalEstimate {
+    /// `unstaked_epoch_height` is the epoch of the last `unstake`. When unknown the current
+    /// epoch is assumed, so the estimate is an upper bound.
+    pub fn new(
+        unstaked_balance: NearToken,
+        is_available: bool,
+        unstaked_epoch_height: Option<EpochHeight>,
+        epoch_info: &EpochInfo,
+    ) -> Self {
+        let epoch_height = match is_available {
+            true => epoch_info.epoch_height,
+            false => {
+                let unstaked_epoch_height =
+                    unstaked_epoch_height.unwrap_or(epoch_info.epoch_height);
+
+                // Not available yet, so it cannot be before the next epoch.
+                (unstaked_epoch_height + NUM_EPOCHS_TO_UNLOCK).max(epoch_info.epoch_height + 1)
+            }
+        };
+
+        Self {
+            unstaked_balance,
+            is_available,
+            epoch_height,
+            remaining_blocks: epoch_info.blocks_until_epoch(epoch_height),
+            remaining_time: epoch_info.time_until_epoch(epoch_height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_withdrawal_estimate() {
+        let epoch_info = EpochInfo {
+            epoch_height: 10,
+            epoch_start_height: 1_000,
+            epoch_length: 100,
+            block_height: 1_050,
+            block_time: Duration::from_secs(1),
+        };
+        let amount = NearToken::from_near(1);
+
+        let estimate = WithdrawalEstimate::new(amount, false, None, &epoch_info);
+        assert_eq!(estimate.epoch_height, 14);
+        assert_eq!(estimate.remaining_blocks, 350);
+        assert_eq!(estimate.remaining_time, Duration::from_secs(350));
+
+        let estimate = WithdrawalEstimate::new(amount, false, Some(8), &epoch_info);
+        assert_eq!(estimate.epoch_height, 12);
+        assert_eq!(estimate.remaining_blocks, 150);
+
+        let estimate = WithdrawalEstimate::new(amount, false, Some(2), &epoch_info);
+        assert_eq!(estimate.epoch_height, 11);
+
+        let estimate = WithdrawalEstimate::new(amount, true, Some(8), &epoch_info);
+        assert_eq!(estimate.epoch_height, 10);
+        assert_eq!(estimate.remaining_time, Duration::ZERO);
+    }
+}
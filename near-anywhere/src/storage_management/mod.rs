@@ -0,0 +1,7 @@
+mod storage_balance;
+mod storage_management;
+
+pub use {
+    storage_balance::{StorageBalance, StorageBalanceBounds},
+    storage_management::StorageManagement,
+};
@@ -0,0 +1,16 @@
+use crate::primitives::types::NearToken;
+
+/// Storage deposit of an account in a contract, per NEP-145.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageBalance {
+    pub total: NearToken,
+    /// Part of `total` not used for storage, it can be withdrawn.
+    pub available: NearToken,
+}
+
+/// Deposit needed to register with a contract, `max` is `None` when it is unbounded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageBalanceBounds {
+    pub min: NearToken,
+    pub max: Option<NearToken>,
+}
@@ -0,0 +1,160 @@
+use {
+    super::{StorageBalance, StorageBalanceBounds},
+    crate::{
+        account::Account,
+        contract::{Contract, ContractError, ContractResult, ONE_YOCTO},
+        primitives::types::{NearGas, NearToken},
+    },
+    serde_json::json,
+};
+
+const STORAGE_GAS: NearGas = NearGas::from_tgas(10);
+
+/// NEP-145 storage management of any contract called through an account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorageManagement {
+    contract: Contract,
+}
+
+impl StorageManagement {
+    pub fn new(account: Account, contract_id: &str) -> Self {
+        Self::from_contract(Contract::new(account, contract_id))
+    }
+
+    pub fn from_contract(contract: Contract) -> Self {
+        Self { contract }
+    }
+
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    pub async fn storage_balance_bounds(&self) -> Result<StorageBalanceBounds, ContractError> {
+        Ok(self
+            .contract
+            .view("storage_balance_bounds", &json!({}))
+            .await?
+            .value)
+    }
+
+    /// `None` when the account is not registered.
+    pub async fn storage_balance_of(
+        &self,
+        account_id: &str,
+    ) -> Result<Option<StorageBalance>, ContractError> {
+        Ok(self
+            .contract
+            .view("storage_balance_of", &json!({ "account_id": account_id }))
+            .await?
+            .value)
+    }
+
+    pub async fn is_registered(&self, account_id: &str) -> Result<bool, ContractError> {
+        Ok(self.storage_balance_of(account_id).await?.is_some())
+    }
+
+    /// Deposits for `account_id`, or the calling account when `None`.
+    pub async fn storage_deposit(
+        &mut self,
+        account_id: Option<&str>,
+        registration_only: bool,
+        deposit: impl Into<NearToken>,
+    ) -> Result<ContractResult<StorageBalance>, ContractError> {
+        let args = json!({
+            "account_id": account_id,
+            "registration_only": registration_only,
+        });
+
+        self.contract
+            .call("storage_deposit", &args, STORAGE_GAS, deposit)
+            .await
+    }
+
+    /// Deposits exactly what `account_id` lacks of the minimum balance. Returns `None` when
+    /// nothing was needed.
+    pub async fn ensure_registered(
+        &mut self,
+        account_id: &str,
+    ) -> Result<Option<ContractResult<StorageBalance>>, ContractError> {
+        let bounds = self.storage_balance_bounds().await?;
+        let balance = self.storage_balance_of(account_id).await?;
+
+        match registration_deposit(&bounds, balance.as_ref()) {
+            Some(deposit) => self
+                .storage_deposit(Some(account_id), balance.is_none(), deposit)
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Withdraws `amount` of the available balance, all of it when `None`.
+    pub async fn storage_withdraw(
+        &mut self,
+        amount: Option<NearToken>,
+    ) -> Result<ContractResult<StorageBalance>, ContractError> {
+        self.contract
+            .call(
+                "storage_withdraw",
+                &json!({ "amount": amount }),
+                STORAGE_GAS,
+                ONE_YOCTO,
+            )
+            .await
+    }
+
+    /// Unregisters the calling account and refunds its deposit. Contracts refuse while the
+    /// account holds assets unless `force` is set, which burns them. Returns `false` when the
+    /// account was not registered.
+    pub async fn storage_unregister(
+        &mut self,
+        force: Option<bool>,
+    ) -> Result<ContractResult<bool>, ContractError> {
+        self.contract
+            .call(
+                "storage_unregister",
+                &json!({ "force": force }),
+                STORAGE_GAS,
+                ONE_YOCTO,
+            )
+            .await
+    }
+}
+
+/// Deposit bringing `balance` up to the minimum, `None` when the account is registered and
+/// has it already.
+fn registration_deposit(
+    bounds: &StorageBalanceBounds,
+    balance: Option<&StorageBalance>,
+) -> Option<NearToken> {
+    match balance {
+        Some(balance) if balance.total >= bounds.min => None,
+        Some(balance) => Some(bounds.min.saturating_sub(balance.total)),
+        None => Some(bounds.min),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_registration_deposit() {
+        let bounds = StorageBalanceBounds {
+            min: NearToken::from_millinear(125),
+            max: None,
+        };
+        let balance = |total| StorageBalance {
+            total: NearToken::from_millinear(total),
+            available: NearToken::ZERO,
+        };
+
+        assert_eq!(registration_deposit(&bounds, None), Some(bounds.min));
+        assert_eq!(
+            registration_deposit(&bounds, Some(&balance(100))),
+            Some(NearToken::from_millinear(25))
+        );
+        assert_eq!(registration_deposit(&bounds, Some(&balance(125))), None);
+        assert_eq!(registration_deposit(&bounds, Some(&balance(200))), None);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::primitives::{
+    account::AccessKeyPermission,
+    actions::Action,
+    runtime::fees::{Fee, RuntimeFeesConfig},
+    transaction::Transaction,
+    types::{Balance, GasPriceView, NearGas, NearToken},
+};
+
+/// Gas burnt for sending and executing a receipt or an action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionFee {
+    pub send: NearGas,
+    pub exec: NearGas,
+}
+
+impl ActionFee {
+    fn new(fee: &Fee, sender_is_receiver: bool, bytes: u64) -> Self {
+        Self {
+            send: NearGas::from_gas(fee.send_fee(sender_is_receiver) * bytes),
+            exec: NearGas::from_gas(fee.exec_fee() * bytes),
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            send: self.send.saturating_add(other.send),
+            exec: self.exec.saturating_add(other.exec),
+        }
+    }
+
+    pub fn total(&self) -> NearGas {
+        self.send.saturating_add(self.exec)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionFeeEstimate {
+    /// Fee of the action receipt the transaction is converted to.
+    pub receipt: ActionFee,
+    /// Fee of each action, in the order of the transaction.
+    pub actions: Vec<ActionFee>,
+    /// Gas attached to function calls, the unused part is refunded.
+    pub prepaid_gas: NearGas,
+    /// Amount attached to transfers and function calls.
+    pub deposit: NearToken,
+    /// yoctoNEAR per gas.
+    pub gas_price: Balance,
+}
+
+impl TransactionFeeEstimate {
+    pub fn send_gas(&self) -> NearGas {
+        self.actions.iter().fold(self.receipt.send, |gas, action| {
+            gas.saturating_add(action.send)
+        })
+    }
+
+    pub fn exec_gas(&self) -> NearGas {
+        self.actions.iter().fold(self.receipt.exec, |gas, action| {
+            gas.saturating_add(action.exec)
+        })
+    }
+
+    /// Gas burnt for sending and executing the transaction, without the prepaid gas.
+    pub fn burnt_gas(&self) -> NearGas {
+        self.send_gas().saturating_add(self.exec_gas())
+    }
+
+    /// Fee burnt for sending and executing the transaction.
+    pub fn fee(&self) -> NearToken {
+        self.gas_cost(self.burnt_gas())
+    }
+
+    /// Most the signer pays for gas, the prepaid gas of function calls included.
+    pub fn max_fee(&self) -> NearToken {
+        self.gas_cost(self.burnt_gas().saturating_add(self.prepaid_gas))
+    }
+
+    /// Most the transaction takes from the signer, deposits included.
+    pub fn max_cost(&self) -> NearToken {
+        self.max_fee().saturating_add(self.deposit)
+    }
+
+    fn gas_cost(&self, gas: NearGas) -> NearToken {
+        NearToken::from_yoctonear((gas.as_gas() as Balance).saturating_mul(self.gas_price))
+    }
+}
+
+/// Estimates the fees of `transaction` with the `transaction_costs` of the runtime config, at
+/// the gas price of `gas_price`.
+pub fn estimate_transaction_fees(
+    transaction: &Transaction,
+    fees_config: &RuntimeFeesConfig,
+    gas_price: &GasPriceView,
+) -> TransactionFeeEstimate {
+    let sender_is_receiver = transaction.signer_id == transaction.receiver_id;
+    let mut prepaid_gas = NearGas::ZERO;
+    let mut deposit = NearToken::ZERO;
+    let actions = transaction
+        .actions
+        .iter()
+        .map(|action| {
+            match action {
+                Action::FunctionCall(action) => {
+                    prepaid_gas = prepaid_gas.saturating_add(action.gas.into());
+                    deposit = deposit.saturating_add(action.deposit.into());
+                }
+                Action::Transfer(action) => {
+                    deposit = deposit.saturating_add(action.deposit.into());
+                }
+                _ => {}
+            }
+
+            estimate_action_fee(action, fees_config, sender_is_receiver)
+        })
+        .collect();
+
+    TransactionFeeEstimate {
+        receipt: ActionFee::new(
+            &fees_config.action_receipt_creation_config,
+            sender_is_receiver,
+            1,
+        ),
+        actions,
+        prepaid_gas,
+        deposit,
+        gas_price: gas_price.gas_price,
+    }
+}
+
+/// Send and exec fees of an action, as the runtime charges them.
+fn estimate_action_fee(
+    action: &Action,
+    fees_config: &RuntimeFeesConfig,
+    sender_is_receiver: bool,
+) -> ActionFee {
+    let config = &fees_config.action_creation_config;
+    let fee = |fee: &Fee| ActionFee::new(fee, sender_is_receiver, 1);
+    let fee_per_byte =
+        |fee: &Fee, bytes: usize| ActionFee::new(fee, sender_is_receiver, bytes as u64);
+
+    match action {
+        Action::CreateAccount(_) => fee(&config.create_account_cost),
+        Action::DeployContract(action) => fee(&config.deploy_contract_cost).add(fee_per_byte(
+            &config.deploy_contract_cost_per_byte,
+            action.code.len(),
+        )),
+        Action::FunctionCall(action) => fee(&config.function_call_cost).add(fee_per_byte(
+            &config.function_call_cost_per_byte,
+            action.method_name.len() + action.args.len(),
+        )),
+        Action::Transfer(_) => fee(&config.transfer_cost),
+        Action::Stake(_) => fee(&config.stake_cost),
+        Action::AddKey(action) => match &action.access_key.permission {
+            AccessKeyPermission::FunctionCall(permission) => {
+                // Each method name is charged with a separator byte.
+                let bytes = permission
+                    .method_names
+                    .iter()
+                    .map(|name| name.len() + 1)
+                    .sum();
+
+                fee(&config.add_key_cost.function_call_cost).add(fee_per_byte(
+                    &config.add_key_cost.function_call_cost_per_byte,
+                    bytes,
+                ))
+            }
+            AccessKeyPermission::FullAccess => fee(&config.add_key_cost.full_access_cost),
+        },
+        Action::DeleteKey(_) => fee(&config.delete_key_cost),
+        Action::DeleteAccount(_) => fee(&config.delete_account_cost),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            crypto::KeyType,
+            hash::CryptoHash,
+            key_pair::KeyPair,
+            primitives::actions::{FunctionCallAction, TransferAction},
+            transaction::create_transaction,
+        },
+    };
+
+    #[test]
+    pub fn test_estimate_transaction_fees() {
+        let fees_config = RuntimeFeesConfig::test();
+        let actions = vec![
+            Action::Transfer(TransferAction {
+                deposit: NearToken::from_near(1).as_yoctonear(),
+            }),
+            Action::FunctionCall(FunctionCallAction {
+                method_name: "vote".to_string(),
+                args: b"{}".to_vec(),
+                gas: NearGas::from_tgas(30).as_gas(),
+                deposit: 0,
+            }),
+        ];
+        let transaction = create_transaction(
+            "alice.near",
+            KeyPair::from_random(KeyType::ED25519).public_key(),
+            "app.near",
+            1,
+            actions,
+            CryptoHash::default(),
+        );
+        let gas_price = GasPriceView {
+            gas_price: 100_000_000,
+        };
+        let estimate = estimate_transaction_fees(&transaction, &fees_config, &gas_price);
+        let config = &fees_config.action_creation_config;
+        let function_call_fee = config.function_call_cost.send_not_sir
+            + config.function_call_cost_per_byte.send_not_sir * 6;
+
+        assert_eq!(
+            estimate.actions[0].send.as_gas(),
+            config.transfer_cost.send_not_sir
+        );
+        assert_eq!(estimate.actions[1].send.as_gas(), function_call_fee);
+        assert_eq!(
+            estimate.send_gas().as_gas(),
+            fees_config.action_receipt_creation_config.send_not_sir
+                + config.transfer_cost.send_not_sir
+                + function_call_fee
+        );
+        assert_eq!(estimate.prepaid_gas, NearGas::from_tgas(30));
+        assert_eq!(estimate.deposit, NearToken::from_near(1));
+        assert_eq!(
+            estimate.fee().as_yoctonear(),
+            estimate.burnt_gas().as_gas() as Balance * 100_000_000
+        );
+        assert_eq!(
+            estimate.max_cost(),
+            NearToken::from_near(1)
+                .saturating_add(estimate.fee())
+                .saturating_add(NearToken::from_millinear(3))
+        );
+    }
+}
@@ -1,7 +1,9 @@
 mod create_transaction;
+mod estimate_fees;
 mod sign_transaction;
 
 pub use {
     create_transaction::create_transaction,
+    estimate_fees::{estimate_transaction_fees, ActionFee, TransactionFeeEstimate},
     sign_transaction::{sign_transaction, sign_transaction_with_receiver},
 };
@@ -6,38 +6,42 @@ use {
             actions::Action,
             transaction::{SignedTransaction, Transaction},
         },
-        signer::Signer,
+        signer::{Signer, SignerError},
     },
     borsh::BorshSerialize,
 };
 
+/// Signs with the key of the public key of the transaction.
 pub async fn sign_transaction(
     transaction: Transaction,
-    signer: Signer,
+    signer: &dyn Signer,
     account_id: &str,
     network_id: &str,
-) -> SignedTransaction {
+) -> Result<SignedTransaction, SignerError> {
     let message = transaction
         .try_to_vec()
         .expect("fail to serialize the transaction");
-    let signature = signer.sign_message(&message, account_id, network_id);
+    let signature = signer
+        .sign_message_with_public_key(&message, account_id, network_id, &transaction.public_key)
+        .await?;
 
     let mut signed_transaction = SignedTransaction::new(signature, transaction);
     signed_transaction.init();
 
-    signed_transaction
+    Ok(signed_transaction)
 }
 
+/// Signs with the default key of the account.
 pub async fn sign_transaction_with_receiver(
     receiver_id: &str,
     nonce: u64,
     actions: Vec<Action>,
     block_hash: CryptoHash,
-    signer: Signer,
+    signer: &dyn Signer,
     account_id: &str,
     network_id: &str,
-) -> SignedTransaction {
-    let public_key = signer.get_public_key(account_id, network_id).unwrap();
+) -> Result<SignedTransaction, SignerError> {
+    let public_key = signer.get_public_key(account_id, network_id).await?;
     let transaction = create_transaction(
         account_id,
         public_key,
@@ -49,9 +53,11 @@ pub async fn sign_transaction_with_receiver(
     let message = transaction
         .try_to_vec()
         .expect("fail to serialize the transaction");
-    let signature = signer.sign_message(&message, account_id, network_id);
+    let signature = signer
+        .sign_message(&message, account_id, network_id)
+        .await?;
     let mut signed_transaction = SignedTransaction::new(signature, transaction);
     signed_transaction.init();
 
-    signed_transaction
+    Ok(signed_transaction)
 }
@@ -0,0 +1,49 @@
+use {
+    near_anywhere::{
+        account::Account,
+        client::Transport,
+        contract::ContractError,
+        crypto::KeyType,
+        key_pair::KeyPair,
+        near::Connection,
+        signer::InMemorySigner,
+    },
+    std::sync::Arc,
+};
+
+#[near_anywhere_macros::contract(gas = "50 TGas")]
+pub trait Counter {
+    #[view]
+    fn get_num(&self) -> i8;
+
+    #[call(deposit = "1 yocto")]
+    fn increment(&mut self, by: u8);
+
+    #[call]
+    fn set_greeting(&mut self, message: String) -> String;
+}
+
+/// Only type-checked, the calls need a network.
+#[allow(dead_code)]
+async fn use_client(counter: &mut CounterClient) -> Result<String, ContractError> {
+    let num = counter.get_num().await?.value;
+    counter.increment(num.unsigned_abs()).await?;
+
+    Ok(counter.set_greeting("hello".to_string()).await?.value)
+}
+
+#[test]
+pub fn test_contract_client() {
+    let keypair = KeyPair::from_random(KeyType::ED25519);
+    let signer = InMemorySigner::from_key_pair("testnet", "alice.testnet", keypair);
+    let connection = Connection::new(
+        "testnet",
+        Transport::http("https://rpc.testnet.near.org"),
+        Arc::new(signer),
+        "jsvm.testnet",
+    );
+    let account = Account::new(connection, "alice.testnet".parse().unwrap());
+    let counter = CounterClient::new(account, "counter.testnet");
+
+    assert_eq!(counter.contract().contract_id(), "counter.testnet");
+}
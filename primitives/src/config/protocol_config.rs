@@ -1,6 +1,8 @@
-use super::RuntimeConfig;
+use {super::RuntimeConfig, crate::types::BlockHeightDelta};
 
 pub struct ProtocolConfig {
     // pub genesis_config: GenesisConfig,
+    /// Blocks per epoch, from the genesis config.
+    pub epoch_length: BlockHeightDelta,
     pub runtime_config: RuntimeConfig,
 }
@@ -1,14 +1,24 @@
-use super::{ProtocolConfig, RuntimeConfig};
+use {
+    super::{ProtocolConfig, RuntimeConfig},
+    crate::types::BlockHeightDelta,
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ProtocolConfigView {
+    pub epoch_length: BlockHeightDelta,
     pub runtime_config: RuntimeConfig,
 }
 
 impl From<ProtocolConfig> for ProtocolConfigView {
     fn from(protocol_config: ProtocolConfig) -> Self {
-        let ProtocolConfig { runtime_config } = protocol_config;
+        let ProtocolConfig {
+            epoch_length,
+            runtime_config,
+        } = protocol_config;
 
-        ProtocolConfigView { runtime_config }
+        ProtocolConfigView {
+            epoch_length,
+            runtime_config,
+        }
     }
 }
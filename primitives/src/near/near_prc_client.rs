@@ -203,12 +203,14 @@ impl NearRpcClient {
         serde_json::from_value(result).unwrap()
     }
 
-    pub async fn validators(&self, block_id: MaybeBlockId) -> EpochValidatorInfo {
+    pub async fn validators(&self, block_id: MaybeBlockId) -> Result<EpochValidatorInfo, RpcError> {
         let params = serde_json::to_value([block_id]).unwrap();
-        let response = self.call_method("validators", Some(params)).await.unwrap();
-        let result = response.result;
+        let response = self.call_method("validators", Some(params)).await?;
 
-        serde_json::from_value(result).unwrap()
+        serde_json::from_value(response.result).map_err(|error| RpcError {
+            code: 0,
+            message: format!("invalid validators response: {}", error),
+        })
     }
 
     pub async fn network_info(&self) -> NetworkInfoView {
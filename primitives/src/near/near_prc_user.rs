@@ -6,15 +6,15 @@ use {
         block::BlockView,
         config::{ProtocolConfigView, RpcProtocolConfigRequest},
         contract::ContractCodeView,
-        network::StatusResponse,
-        query::{FunctionArgs, QueryRequest, QueryResponseKind, RpcQueryRequest},
+        network::{EpochValidatorInfo, StatusResponse},
+        query::{FunctionArgs, QueryRequest, QueryResponseKind, RpcQueryRequest, ViewCallError},
         state_change::{
             RpcStateChangesInBlockByTypeRequest,
             StateChangesRequestView,
             StateChangesView,
         },
         transaction::{CallResult, FinalExecutionOutcomeView, SignedTransaction, ViewStateResult},
-        types::{AccountId, BlockReference, StoreKey},
+        types::{AccountId, BlockHeight, BlockReference, GasPriceView, MaybeBlockId, StoreKey},
     },
     json_rpc_client::{RpcError, Transport},
     near_anywhere_crypto::PublicKey,
@@ -44,6 +44,18 @@ impl NearRpcUser {
         self.client.block(block_reference).await
     }
 
+    pub async fn get_gas_price(&self, height: BlockHeight) -> Result<GasPriceView, RpcError> {
+        self.client.gas_price(height).await
+    }
+
+    /// Validators and epoch of `block_id`, the latest block when `None`.
+    pub async fn get_validators(
+        &self,
+        block_id: MaybeBlockId,
+    ) -> Result<EpochValidatorInfo, RpcError> {
+        self.client.validators(block_id).await
+    }
+
     pub async fn get_protocol_config(
         &self,
         block_reference: BlockReference,
@@ -99,8 +111,17 @@ impl NearRpcUser {
     }
 
     pub async fn view_account(&self, account_id: &AccountId) -> Result<AccountView, RpcError> {
+        self.view_account_at(account_id, BlockReference::latest())
+            .await
+    }
+
+    pub async fn view_account_at(
+        &self,
+        account_id: &AccountId,
+        block_reference: BlockReference,
+    ) -> Result<AccountView, RpcError> {
         let request = RpcQueryRequest {
-            block_reference: BlockReference::latest(),
+            block_reference,
             request: QueryRequest::ViewAccount {
                 account_id: account_id.clone(),
             },
@@ -165,7 +186,7 @@ impl NearRpcUser {
         account_id: &AccountId,
         method_name: &str,
         args: FunctionArgs,
-    ) -> Result<CallResult, RpcError> {
+    ) -> Result<CallResult, ViewCallError> {
         let request = RpcQueryRequest {
             block_reference: BlockReference::latest(),
             request: QueryRequest::CallFunction {
@@ -178,10 +199,15 @@ impl NearRpcUser {
 
         match query_response.kind {
             QueryResponseKind::CallResult(call_result) => Ok(call_result),
+            QueryResponseKind::Error(error) => Err(ViewCallError::Execution {
+                error: error.error,
+                logs: error.logs,
+            }),
             _ => Err(RpcError {
                 code: 0,
                 message: "Invalid type of response".into(),
-            }),
+            }
+            .into()),
         }
     }
 }
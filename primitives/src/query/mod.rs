@@ -7,6 +7,7 @@ mod query_response_kind;
 mod rpc_query_error;
 mod rpc_query_request;
 mod rpc_query_response;
+mod view_call_error;
 
 pub use {
     function_args::FunctionArgs,
@@ -18,4 +19,5 @@ pub use {
     rpc_query_error::RpcQueryError,
     rpc_query_request::RpcQueryRequest,
     rpc_query_response::RpcQueryResponse,
+    view_call_error::ViewCallError,
 };
@@ -1,4 +1,5 @@
 use {
+    super::QueryError,
     crate::{
         access_key::{AccessKeyList, AccessKeyView},
         account::AccountView,
@@ -17,4 +18,6 @@ pub enum QueryResponseKind {
     CallResult(CallResult),
     AccessKey(AccessKeyView),
     AccessKeyList(AccessKeyList),
+    /// Failed view calls, e.g. a contract panic.
+    Error(QueryError),
 }
@@ -0,0 +1,33 @@
+use json_rpc_client::RpcError;
+
+/// Failure of a view call, `Execution` keeps the logs the contract wrote before failing.
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum ViewCallError {
+    #[error("{}", error.message)]
+    Rpc { error: RpcError },
+    #[error("{error}")]
+    Execution { error: String, logs: Vec<String> },
+    #[error("invalid account id '{account_id}': {error_message}")]
+    InvalidAccountId {
+        account_id: String,
+        error_message: String,
+    },
+}
+
+impl From<RpcError> for ViewCallError {
+    fn from(error: RpcError) -> Self {
+        Self::Rpc { error }
+    }
+}
+
+impl From<ViewCallError> for RpcError {
+    fn from(error: ViewCallError) -> Self {
+        match error {
+            ViewCallError::Rpc { error } => error,
+            error => Self {
+                code: 0,
+                message: error.to_string(),
+            },
+        }
+    }
+}
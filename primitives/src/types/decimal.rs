@@ -0,0 +1,106 @@
+//! Decimal helpers shared by the amount types.
+
+pub(crate) enum DecimalError {
+    Invalid,
+    TooManyDecimals,
+    Overflow,
+}
+
+/// Splits `1.5 NEAR` into the amount without `_` separators and the lowercase unit.
+pub(crate) fn split_unit(value: &str) -> (String, String) {
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '_'))
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+
+    (amount.replace('_', ""), unit.trim().to_ascii_lowercase())
+}
+
+/// Parses `amount` as a decimal number scaled by 10^`decimals`.
+pub(crate) fn parse_decimal(amount: &str, decimals: usize) -> Result<u128, DecimalError> {
+    let (whole, fraction) = match amount.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (amount, ""),
+    };
+
+    if (whole.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+        return Err(DecimalError::Invalid);
+    }
+    if fraction.len() > decimals {
+        return Err(DecimalError::TooManyDecimals);
+    }
+
+    let whole = match whole.is_empty() {
+        true => 0,
+        false => whole.parse::<u128>().map_err(|_| DecimalError::Overflow)?,
+    };
+    let fraction = match fraction.trim_end_matches('0') {
+        "" => 0,
+        trimmed => trimmed
+            .parse::<u128>()
+            .map_err(|_| DecimalError::Overflow)?
+            .checked_mul(
+                10u128
+                    .checked_pow((decimals - trimmed.len()) as u32)
+                    .ok_or(DecimalError::Overflow)?,
+            )
+            .ok_or(DecimalError::Overflow)?,
+    };
+
+    match (whole, 10u128.checked_pow(decimals as u32)) {
+        (0, _) => Some(fraction),
+        (whole, Some(scale)) => whole
+            .checked_mul(scale)
+            .and_then(|whole| whole.checked_add(fraction)),
+        (_, None) => None,
+    }
+    .ok_or(DecimalError::Overflow)
+}
+
+/// Formats `value` scaled by 10^`decimals` rounded to `shown` places, without trailing zeros.
+pub fn format_decimal(value: u128, decimals: usize, shown: usize) -> String {
+    let shown = shown.min(decimals);
+    // Powers of ten past `u128::MAX` exceed any value, which rounds to zero or has no whole part.
+    let rounded = match 10u128.checked_pow((decimals - shown) as u32) {
+        Some(unit) if unit > 1 && value % unit >= unit / 2 => value / unit + 1,
+        Some(unit) => value / unit,
+        None => 0,
+    };
+    let (whole, fraction) = match 10u128.checked_pow(shown as u32) {
+        Some(scale) => (rounded / scale, rounded % scale),
+        None => (0, rounded),
+    };
+    let fraction = format!("{:0width$}", fraction, width = shown);
+    let fraction = fraction.trim_end_matches('0');
+
+    match fraction.is_empty() {
+        true => whole.to_string(),
+        false => format!("{}.{}", whole, fraction),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_large_decimals() {
+        assert_eq!(format_decimal(15, 1, 1), "1.5");
+        assert_eq!(format_decimal(u128::MAX, 40, 2), "0.03");
+        assert_eq!(format_decimal(u128::MAX, 60, 2), "0");
+        assert_eq!(format_decimal(5, 60, 60), format!("0.{}5", "0".repeat(59)));
+        assert_eq!(format_decimal(u128::MAX, 255, 10), "0");
+
+        assert!(matches!(parse_decimal("0.01", 40), Ok(value) if value == 10u128.pow(38)));
+        assert!(matches!(parse_decimal("0", 60), Ok(0)));
+        assert!(matches!(parse_decimal("0.50", 2), Ok(50)));
+        assert!(matches!(
+            parse_decimal("1", 40),
+            Err(DecimalError::Overflow)
+        ));
+        assert!(matches!(
+            parse_decimal("0.5", 60),
+            Err(DecimalError::Overflow)
+        ));
+    }
+}
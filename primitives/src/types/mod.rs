@@ -4,9 +4,14 @@ mod block_id;
 mod block_reference;
 mod block_shard_id;
 mod cost_gas_used;
+mod decimal;
 mod epoch_id;
 mod finality;
 mod gas_price_view;
+mod near_gas;
+mod near_gas_error;
+mod near_token;
+mod near_token_error;
 mod store_key;
 mod store_value;
 
@@ -16,10 +21,15 @@ pub use {
     block_shard_id::BlockShardId,
     chrono::DateTime,
     cost_gas_used::CostGasUsed,
+    decimal::format_decimal,
     epoch_id::EpochId,
     finality::Finality,
     gas_price_view::GasPriceView,
     near_primitives_core::types::*,
+    near_gas::NearGas,
+    near_gas_error::NearGasError,
+    near_token::NearToken,
+    near_token_error::NearTokenError,
     store_key::StoreKey,
     store_value::StoreValue,
 };
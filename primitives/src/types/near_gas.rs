@@ -0,0 +1,188 @@
+use {
+    super::{
+        decimal::{format_decimal, parse_decimal, split_unit, DecimalError},
+        Gas,
+        NearGasError,
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    core::{fmt, str::FromStr},
+};
+
+const GGAS_DECIMALS: usize = 9;
+const TGAS_DECIMALS: usize = 12;
+const GAS_PER_GGAS: Gas = 10u64.pow(GGAS_DECIMALS as u32);
+const GAS_PER_TGAS: Gas = 10u64.pow(TGAS_DECIMALS as u32);
+
+/// An amount of gas, parses from strings like `30 TGas`, `5GGas` or `1000 gas`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(transparent)]
+pub struct NearGas(Gas);
+
+impl NearGas {
+    pub const ZERO: NearGas = NearGas(0);
+
+    pub const fn from_gas(gas: Gas) -> Self {
+        Self(gas)
+    }
+
+    /// Panics on overflow, amounts above 1.8e10 GGas.
+    pub const fn from_ggas(ggas: Gas) -> Self {
+        Self(ggas * GAS_PER_GGAS)
+    }
+
+    /// Panics on overflow, amounts above 1.8e7 TGas.
+    pub const fn from_tgas(tgas: Gas) -> Self {
+        Self(tgas * GAS_PER_TGAS)
+    }
+
+    pub const fn as_gas(&self) -> Gas {
+        self.0
+    }
+
+    /// Whole GGas, the fraction is truncated.
+    pub const fn as_ggas(&self) -> Gas {
+        self.0 / GAS_PER_GGAS
+    }
+
+    /// Whole TGas, the fraction is truncated.
+    pub const fn as_tgas(&self) -> Gas {
+        self.0 / GAS_PER_TGAS
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    pub fn checked_mul(self, factor: u64) -> Option<Self> {
+        self.0.checked_mul(factor).map(Self)
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Formats the amount in TGas rounded to `decimals` places, without trailing zeros and unit.
+    pub fn format_tgas(&self, decimals: usize) -> String {
+        format_decimal(self.0 as u128, TGAS_DECIMALS, decimals)
+    }
+}
+
+/// Uses the largest unit the amount fills, e.g. `2.5 TGas` or `300 gas`.
+impl fmt::Display for NearGas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            gas if gas >= GAS_PER_TGAS => write!(f, "{} TGas", self.format_tgas(TGAS_DECIMALS)),
+            gas if gas >= GAS_PER_GGAS => write!(
+                f,
+                "{} GGas",
+                format_decimal(gas as u128, GGAS_DECIMALS, GGAS_DECIMALS)
+            ),
+            gas => write!(f, "{} gas", gas),
+        }
+    }
+}
+
+impl FromStr for NearGas {
+    type Err = NearGasError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let (amount, unit) = split_unit(value);
+        let decimals = match unit.as_str() {
+            "" => Err(NearGasError::MissingUnit {
+                amount: value.to_string(),
+            })?,
+            "tgas" => TGAS_DECIMALS,
+            "ggas" => GGAS_DECIMALS,
+            "gas" => 0,
+            _ => Err(NearGasError::UnknownUnit { unit })?,
+        };
+        let overflow = || NearGasError::Overflow {
+            amount: value.to_string(),
+        };
+
+        parse_decimal(&amount, decimals)
+            .map_err(|error| {
+                let amount = value.to_string();
+                match error {
+                    DecimalError::Invalid => NearGasError::InvalidAmount { amount },
+                    DecimalError::TooManyDecimals => NearGasError::TooManyDecimals { amount },
+                    DecimalError::Overflow => NearGasError::Overflow { amount },
+                }
+            })
+            .and_then(|gas| Gas::try_from(gas).map_err(|_| overflow()))
+            .map(Self)
+    }
+}
+
+impl From<Gas> for NearGas {
+    fn from(gas: Gas) -> Self {
+        Self(gas)
+    }
+}
+
+impl From<NearGas> for Gas {
+    fn from(gas: NearGas) -> Self {
+        gas.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_and_display() {
+        assert_eq!(
+            "30 TGas".parse::<NearGas>().unwrap(),
+            NearGas::from_tgas(30)
+        );
+        assert_eq!(
+            "2.5tgas".parse::<NearGas>().unwrap(),
+            NearGas::from_ggas(2_500)
+        );
+        assert_eq!(
+            "1000 gas".parse::<NearGas>().unwrap(),
+            NearGas::from_gas(1_000)
+        );
+        assert!(matches!(
+            "30".parse::<NearGas>(),
+            Err(NearGasError::MissingUnit { .. })
+        ));
+        assert!(matches!(
+            "0.5 gas".parse::<NearGas>(),
+            Err(NearGasError::TooManyDecimals { .. })
+        ));
+        assert!(matches!(
+            "20000000 TGas".parse::<NearGas>(),
+            Err(NearGasError::Overflow { .. })
+        ));
+
+        assert_eq!(NearGas::from_ggas(2_500).to_string(), "2.5 TGas");
+        assert_eq!(NearGas::from_ggas(5).to_string(), "5 GGas");
+        assert_eq!(NearGas::from_gas(300).to_string(), "300 gas");
+        assert_eq!(NearGas::from_gas(2_345_678_901_234).format_tgas(2), "2.35");
+    }
+}
@@ -0,0 +1,13 @@
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum NearGasError {
+    #[error("invalid gas '{amount}'")]
+    InvalidAmount { amount: String },
+    #[error("missing unit in '{amount}', expected TGas, GGas or gas")]
+    MissingUnit { amount: String },
+    #[error("unknown unit '{unit}', expected TGas, GGas or gas")]
+    UnknownUnit { unit: String },
+    #[error("'{amount}' is more precise than 1 gas")]
+    TooManyDecimals { amount: String },
+    #[error("'{amount}' is too large")]
+    Overflow { amount: String },
+}
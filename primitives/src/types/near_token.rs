@@ -0,0 +1,256 @@
+use {
+    super::{
+        decimal::{format_decimal, parse_decimal, split_unit, DecimalError},
+        Balance,
+        NearTokenError,
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    core::{fmt, str::FromStr},
+    serde::{de, Deserializer, Serializer},
+};
+
+const NEAR_DECIMALS: usize = 24;
+const YOCTO_PER_NEAR: Balance = 10u128.pow(NEAR_DECIMALS as u32);
+
+/// An amount of NEAR, stored in yoctoNEAR.
+///
+/// Parses from strings like `1.5 NEAR`, `0.25N` or `100 yocto`. Serializes as a decimal string
+/// of yoctoNEAR and deserializes from either that string or a number, as NEAR RPC does.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub struct NearToken(Balance);
+
+impl NearToken {
+    pub const ZERO: NearToken = NearToken(0);
+
+    pub const fn from_yoctonear(amount: Balance) -> Self {
+        Self(amount)
+    }
+
+    /// Panics on overflow, amounts above 3.4e14 NEAR.
+    pub const fn from_near(amount: Balance) -> Self {
+        Self(amount * YOCTO_PER_NEAR)
+    }
+
+    /// Panics on overflow, amounts above 3.4e17 milliNEAR.
+    pub const fn from_millinear(amount: Balance) -> Self {
+        Self(amount * (YOCTO_PER_NEAR / 1_000))
+    }
+
+    pub const fn as_yoctonear(&self) -> Balance {
+        self.0
+    }
+
+    /// Whole NEAR, the fraction is truncated.
+    pub const fn as_near(&self) -> Balance {
+        self.0 / YOCTO_PER_NEAR
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    pub fn checked_mul(self, factor: u128) -> Option<Self> {
+        self.0.checked_mul(factor).map(Self)
+    }
+
+    pub fn checked_div(self, divisor: u128) -> Option<Self> {
+        self.0.checked_div(divisor).map(Self)
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+
+    /// Formats the amount in NEAR rounded to `decimals` places, without trailing zeros and unit.
+    pub fn format(&self, decimals: usize) -> String {
+        format_decimal(self.0, NEAR_DECIMALS, decimals)
+    }
+}
+
+impl fmt::Display for NearToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} NEAR", self.format(NEAR_DECIMALS))
+    }
+}
+
+impl FromStr for NearToken {
+    type Err = NearTokenError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let (amount, unit) = split_unit(value);
+        let decimals = match unit.as_str() {
+            "" => Err(NearTokenError::MissingUnit {
+                amount: value.to_string(),
+            })?,
+            "near" | "n" => NEAR_DECIMALS,
+            "yocto" | "yoctonear" => 0,
+            _ => Err(NearTokenError::UnknownUnit { unit })?,
+        };
+
+        parse_decimal(&amount, decimals).map(Self).map_err(|error| {
+            let amount = value.to_string();
+            match error {
+                DecimalError::Invalid => NearTokenError::InvalidAmount { amount },
+                DecimalError::TooManyDecimals => NearTokenError::TooManyDecimals { amount },
+                DecimalError::Overflow => NearTokenError::Overflow { amount },
+            }
+        })
+    }
+}
+
+impl From<Balance> for NearToken {
+    fn from(amount: Balance) -> Self {
+        Self(amount)
+    }
+}
+
+impl From<NearToken> for Balance {
+    fn from(amount: NearToken) -> Self {
+        amount.0
+    }
+}
+
+impl serde::Serialize for NearToken {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for NearToken {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NearTokenVisitor;
+
+        impl<'de> de::Visitor<'de> for NearTokenVisitor {
+            type Value = NearToken;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a yoctoNEAR amount as a decimal string or a number")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map(NearToken).map_err(de::Error::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(NearToken(value as Balance))
+            }
+
+            fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+                Ok(NearToken(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value)
+                    .map_err(de::Error::custom)
+                    .and_then(|value| self.visit_u64(value))
+            }
+        }
+
+        deserializer.deserialize_any(NearTokenVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_parse() {
+        let expected = NearToken::from_millinear(1_500);
+
+        assert_eq!("1.5 NEAR".parse::<NearToken>().unwrap(), expected);
+        assert_eq!("1.5near".parse::<NearToken>().unwrap(), expected);
+        assert_eq!(
+            "0.25N".parse::<NearToken>().unwrap(),
+            NearToken::from_millinear(250)
+        );
+        assert_eq!(
+            "100 yocto".parse::<NearToken>().unwrap(),
+            NearToken::from_yoctonear(100)
+        );
+        assert_eq!(
+            "1.5".parse::<NearToken>(),
+            Err(NearTokenError::MissingUnit {
+                amount: "1.5".to_string()
+            })
+        );
+        assert!(matches!(
+            "1.5 yocto".parse::<NearToken>(),
+            Err(NearTokenError::TooManyDecimals { .. })
+        ));
+        assert!(matches!(
+            "1 mNEAR".parse::<NearToken>(),
+            Err(NearTokenError::UnknownUnit { .. })
+        ));
+        assert!(matches!(
+            "1000000000000000 NEAR".parse::<NearToken>(),
+            Err(NearTokenError::Overflow { .. })
+        ));
+    }
+
+    #[test]
+    pub fn test_format() {
+        let amount = NearToken::from_yoctonear(1_234_567 * 10u128.pow(18));
+
+        assert_eq!(amount.to_string(), "1.234567 NEAR");
+        assert_eq!(amount.format(2), "1.23");
+        assert_eq!(amount.format(4), "1.2346");
+        assert_eq!(amount.format(0), "1");
+        assert_eq!(NearToken::ZERO.format(5), "0");
+        assert_eq!(
+            NearToken::from_yoctonear(1).to_string(),
+            "0.000000000000000000000001 NEAR"
+        );
+    }
+
+    #[test]
+    pub fn test_checked_arithmetic() {
+        let amount = NearToken::from_near(1);
+
+        assert_eq!(amount.checked_sub(NearToken::from_near(2)), None);
+        assert_eq!(
+            NearToken::from_yoctonear(u128::MAX).checked_add(NearToken::from_yoctonear(1)),
+            None
+        );
+        assert_eq!(amount.checked_mul(3), Some(NearToken::from_near(3)));
+        assert_eq!(amount.checked_div(0), None);
+    }
+
+    #[test]
+    pub fn test_serde() {
+        let amount = NearToken::from_near(2);
+        let json = serde_json::to_string(&amount).unwrap();
+
+        assert_eq!(json, "\"2000000000000000000000000\"");
+        assert_eq!(serde_json::from_str::<NearToken>(&json).unwrap(), amount);
+        assert_eq!(
+            serde_json::from_str::<NearToken>("1000").unwrap(),
+            NearToken::from_yoctonear(1_000)
+        );
+    }
+}
@@ -0,0 +1,13 @@
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum NearTokenError {
+    #[error("invalid amount '{amount}'")]
+    InvalidAmount { amount: String },
+    #[error("missing unit in '{amount}', expected NEAR, N or yocto")]
+    MissingUnit { amount: String },
+    #[error("unknown unit '{unit}', expected NEAR, N or yocto")]
+    UnknownUnit { unit: String },
+    #[error("'{amount}' has more than 24 decimals")]
+    TooManyDecimals { amount: String },
+    #[error("'{amount}' is too large")]
+    Overflow { amount: String },
+}
@@ -0,0 +1,161 @@
+use {
+    crate::errors::ConfigError,
+    near_anywhere::primitives::{actions::Action, transaction::Transaction, types::NearToken},
+    zeroize::Zeroizing,
+};
+
+const WILDCARD: &str = "*";
+
+/// Holds secret keys, so it is deliberately not `Debug`.
+#[derive(Deserialize, Clone)]
+pub struct SigningServerConfig {
+    pub listen_address: String,
+    pub auth_token: String,
+    pub network_id: String,
+    pub accounts: Vec<AccountConfig>,
+}
+
+/// Key and allow-lists of an account, `"*"` allows any receiver or method.
+#[derive(Deserialize, Clone)]
+pub struct AccountConfig {
+    pub account_id: String,
+    pub secret_key: String,
+    pub allowed_receivers: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    /// Most yoctoNEAR the function calls of a transaction may attach in total, unlimited when
+    /// not set.
+    #[serde(default)]
+    pub max_deposit: Option<NearToken>,
+}
+
+impl SigningServerConfig {
+    pub fn from_file(path: &str) -> Result<Self, ConfigError> {
+        let data =
+            Zeroizing::new(
+                std::fs::read_to_string(path).map_err(|err| ConfigError::Read {
+                    error_message: err.to_string(),
+                })?,
+            );
+
+        serde_json::from_str(&data).map_err(|err| ConfigError::Invalid {
+            error_message: err.to_string(),
+        })
+    }
+}
+
+impl AccountConfig {
+    fn is_allowed(allow_list: &[String], value: &str) -> bool {
+        allow_list
+            .iter()
+            .any(|item| item == WILDCARD || item == value)
+    }
+
+    /// Only transactions made of function calls to allowed receivers and methods, within the
+    /// max deposit, are signed.
+    pub fn check_transaction(&self, transaction: &Transaction) -> Result<(), String> {
+        if transaction.signer_id.as_str() != self.account_id {
+            return Err(format!(
+                "transaction is signed by '{}' instead of '{}'",
+                transaction.signer_id, self.account_id
+            ));
+        }
+        if !Self::is_allowed(&self.allowed_receivers, transaction.receiver_id.as_str()) {
+            return Err(format!(
+                "receiver '{}' is not allowed",
+                transaction.receiver_id
+            ));
+        }
+
+        let mut deposit = NearToken::ZERO;
+        for action in transaction.actions.iter() {
+            match action {
+                Action::FunctionCall(function_call) => {
+                    if !Self::is_allowed(&self.allowed_methods, &function_call.method_name) {
+                        return Err(format!(
+                            "method '{}' is not allowed",
+                            function_call.method_name
+                        ));
+                    }
+                    deposit = deposit.saturating_add(function_call.deposit.into());
+                }
+                _ => return Err("only function call actions are allowed".to_string()),
+            }
+        }
+
+        match self.max_deposit {
+            Some(max_deposit) if deposit > max_deposit => Err(format!(
+                "deposit {} exceeds the max deposit {}",
+                deposit, max_deposit
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        near_anywhere::{
+            crypto::{KeyType, PublicKey},
+            hash::CryptoHash,
+            primitives::actions::FunctionCallAction,
+            transaction::create_transaction,
+        },
+    };
+
+    fn transaction(deposits: &[u128]) -> Transaction {
+        let actions = deposits
+            .iter()
+            .map(|deposit| {
+                Action::FunctionCall(FunctionCallAction {
+                    method_name: "buy".to_string(),
+                    args: vec![],
+                    gas: 30_000_000_000_000,
+                    deposit: *deposit,
+                })
+            })
+            .collect();
+
+        create_transaction(
+            "alice.testnet",
+            PublicKey::empty(KeyType::ED25519),
+            "app.testnet",
+            1,
+            actions,
+            CryptoHash::default(),
+        )
+    }
+
+    #[test]
+    pub fn test_max_deposit() {
+        let mut account = AccountConfig {
+            account_id: "alice.testnet".to_string(),
+            secret_key: String::new(),
+            allowed_receivers: vec!["app.testnet".to_string()],
+            allowed_methods: vec!["*".to_string()],
+            max_deposit: None,
+        };
+        assert!(account
+            .check_transaction(&transaction(&[u128::MAX]))
+            .is_ok());
+
+        account.max_deposit = Some(NearToken::from_yoctonear(100));
+        assert!(account.check_transaction(&transaction(&[100])).is_ok());
+        assert!(account.check_transaction(&transaction(&[101])).is_err());
+        // Deposits add up across the actions of a transaction.
+        assert!(account.check_transaction(&transaction(&[60, 60])).is_err());
+
+        let account: AccountConfig = serde_json::from_str(
+            r#"{
+                "account_id": "alice.testnet",
+                "secret_key": "",
+                "allowed_receivers": ["*"],
+                "allowed_methods": ["*"],
+                "max_deposit": "1000000000000000000000000"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(account.max_deposit, Some(NearToken::from_near(1)));
+    }
+}
@@ -0,0 +1,42 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ConfigError {
+    #[error("fail to read the config: {error_message}")]
+    Read { error_message: String },
+    #[error("invalid config: {error_message}")]
+    Invalid { error_message: String },
+    #[error("invalid secret key of '{account_id}': {error_message}")]
+    InvalidSecretKey {
+        account_id: String,
+        error_message: String,
+    },
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ServerError {
+    #[error("missing or invalid auth token")]
+    Unauthorized,
+    #[error("bad request: {error_message}")]
+    BadRequest { error_message: String },
+    #[error("not allowed: {error_message}")]
+    Forbidden { error_message: String },
+    #[error("signer error: {error_message}")]
+    Signer { error_message: String },
+}
+
+impl IntoResponse for ServerError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            ServerError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ServerError::BadRequest { .. } => StatusCode::BAD_REQUEST,
+            ServerError::Forbidden { .. } => StatusCode::FORBIDDEN,
+            ServerError::Signer { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        (status, self.to_string()).into_response()
+    }
+}
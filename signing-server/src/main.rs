@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate serde_derive;
+
+mod config;
+mod errors;
+mod server;
+
+use {config::SigningServerConfig, std::net::TcpListener};
+
+#[tokio::main]
+async fn main() {
+    let config_path = std::env::args()
+        .nth(1)
+        .expect("usage: near-anywhere-signing-server <config.json>");
+    let config = SigningServerConfig::from_file(&config_path).expect("fail to load the config");
+    let listener = TcpListener::bind(&config.listen_address).expect("fail to bind the address");
+
+    println!("signing server listening on {}", listener.local_addr().unwrap());
+
+    let router = server::router(config).expect("fail to load the config");
+
+    server::serve(router, listener)
+        .await
+        .expect("signing server stopped");
+}
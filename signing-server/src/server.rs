@@ -0,0 +1,297 @@
+use {
+    crate::{
+        config::{AccountConfig, SigningServerConfig},
+        errors::{ConfigError, ServerError},
+    },
+    axum::{
+        extract::State,
+        http::{header::AUTHORIZATION, HeaderMap},
+        routing::post,
+        Json,
+        Router,
+    },
+    borsh::BorshDeserialize,
+    near_anywhere::{
+        key_pair::KeyPair,
+        key_store::{InMemoryKeyStore, KeyStore},
+        primitives::transaction::Transaction,
+        serialize::from_base64,
+        signer::{
+            InMemorySigner,
+            RemotePublicKeyRequest,
+            RemotePublicKeyResponse,
+            RemoteSignRequest,
+            RemoteSignResponse,
+            Signer,
+            REMOTE_SIGNER_PUBLIC_KEY_PATH,
+            REMOTE_SIGNER_SIGN_PATH,
+        },
+    },
+    std::{collections::HashMap, net::TcpListener, sync::Arc},
+    zeroize::Zeroizing,
+};
+
+struct AppState {
+    auth_token: String,
+    network_id: String,
+    accounts: HashMap<String, AccountConfig>,
+    signer: InMemorySigner,
+}
+
+impl AppState {
+    fn new(config: SigningServerConfig) -> Result<Self, ConfigError> {
+        let key_store = InMemoryKeyStore::new();
+        let mut accounts = HashMap::new();
+
+        for mut account in config.accounts {
+            // The key only lives in the key store, the config copy is wiped.
+            let secret_key = Zeroizing::new(std::mem::take(&mut account.secret_key));
+            let key_pair = KeyPair::from_secret_key(&secret_key).map_err(|err| {
+                ConfigError::InvalidSecretKey {
+                    account_id: account.account_id.clone(),
+                    error_message: err.to_string(),
+                }
+            })?;
+            key_store.set_key(&config.network_id, &account.account_id, key_pair);
+            accounts.insert(account.account_id.clone(), account);
+        }
+
+        Ok(Self {
+            auth_token: config.auth_token,
+            network_id: config.network_id,
+            accounts,
+            signer: InMemorySigner::new(KeyStore::InMemoryStorage(key_store)),
+        })
+    }
+
+    fn authorize(&self, headers: &HeaderMap) -> Result<(), ServerError> {
+        let expected = format!("Bearer {}", self.auth_token);
+        let received = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        match constant_time_eq(expected.as_bytes(), received.as_bytes()) {
+            true => Ok(()),
+            false => Err(ServerError::Unauthorized),
+        }
+    }
+
+    fn account(&self, account_id: &str, network_id: &str) -> Result<&AccountConfig, ServerError> {
+        if network_id != self.network_id {
+            return Err(ServerError::BadRequest {
+                error_message: format!("unknown network '{}'", network_id),
+            });
+        }
+
+        self.accounts
+            .get(account_id)
+            .ok_or_else(|| ServerError::Forbidden {
+                error_message: format!("unknown account '{}'", account_id),
+            })
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+async fn public_key(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(request): Json<RemotePublicKeyRequest>,
+) -> Result<Json<RemotePublicKeyResponse>, ServerError> {
+    state.authorize(&headers)?;
+    state.account(&request.account_id, &request.network_id)?;
+
+    let public_key = state
+        .signer
+        .get_public_key(&request.account_id, &request.network_id)
+        .await
+        .map_err(|err| ServerError::Signer {
+            error_message: err.to_string(),
+        })?;
+
+    Ok(Json(RemotePublicKeyResponse { public_key }))
+}
+
+async fn sign(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(request): Json<RemoteSignRequest>,
+) -> Result<Json<RemoteSignResponse>, ServerError> {
+    state.authorize(&headers)?;
+    let account = state.account(&request.account_id, &request.network_id)?;
+
+    let message = from_base64(&request.message).map_err(|err| ServerError::BadRequest {
+        error_message: err.to_string(),
+    })?;
+    let transaction =
+        Transaction::try_from_slice(&message).map_err(|_| ServerError::BadRequest {
+            error_message: "message is not a transaction".to_string(),
+        })?;
+    account
+        .check_transaction(&transaction)
+        .map_err(|error_message| ServerError::Forbidden { error_message })?;
+
+    let signature = state
+        .signer
+        .sign_message(&message, &request.account_id, &request.network_id)
+        .await
+        .map_err(|err| ServerError::Signer {
+            error_message: err.to_string(),
+        })?;
+
+    Ok(Json(RemoteSignResponse { signature }))
+}
+
+/// Fails when a secret key of the config is invalid.
+pub fn router(config: SigningServerConfig) -> Result<Router, ConfigError> {
+    Ok(Router::new()
+        .route(REMOTE_SIGNER_PUBLIC_KEY_PATH, post(public_key))
+        .route(REMOTE_SIGNER_SIGN_PATH, post(sign))
+        .with_state(Arc::new(AppState::new(config)?)))
+}
+
+pub async fn serve(router: Router, listener: TcpListener) -> Result<(), hyper::Error> {
+    axum::Server::from_tcp(listener)?
+        .serve(router.into_make_service())
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        borsh::BorshSerialize,
+        near_anywhere::{
+            crypto::KeyType,
+            hash::CryptoHash,
+            primitives::actions::{Action, FunctionCallAction, TransferAction},
+            signer::RemoteSigner,
+            transaction::create_transaction,
+        },
+    };
+
+    const AUTH_TOKEN: &str = "secret-token";
+    const NETWORK_ID: &str = "testnet";
+    const ACCOUNT_ID: &str = "alice.testnet";
+
+    fn config(secret_key: String) -> SigningServerConfig {
+        SigningServerConfig {
+            listen_address: "127.0.0.1:0".to_string(),
+            auth_token: AUTH_TOKEN.to_string(),
+            network_id: NETWORK_ID.to_string(),
+            accounts: vec![AccountConfig {
+                account_id: ACCOUNT_ID.to_string(),
+                secret_key,
+                allowed_receivers: vec!["app.testnet".to_string()],
+                allowed_methods: vec!["add_message".to_string()],
+                max_deposit: None,
+            }],
+        }
+    }
+
+    fn start_server(key_pair: &KeyPair) -> String {
+        let config = config(key_pair.to_secret_string().to_string());
+        let listener = TcpListener::bind(&config.listen_address).unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(serve(router(config).unwrap(), listener));
+
+        url
+    }
+
+    fn transaction(key_pair: &KeyPair, receiver_id: &str, action: Action) -> Vec<u8> {
+        create_transaction(
+            ACCOUNT_ID,
+            key_pair.public_key(),
+            receiver_id,
+            1,
+            vec![action],
+            CryptoHash::default(),
+        )
+        .try_to_vec()
+        .unwrap()
+    }
+
+    fn function_call(method_name: &str) -> Action {
+        Action::FunctionCall(FunctionCallAction {
+            method_name: method_name.to_string(),
+            args: vec![],
+            gas: 30_000_000_000_000,
+            deposit: 0,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_signs_allowed_transactions() {
+        let key_pair = KeyPair::from_random(KeyType::ED25519);
+        let url = start_server(&key_pair);
+        let signer = RemoteSigner::new(&url, AUTH_TOKEN);
+        let message = transaction(&key_pair, "app.testnet", function_call("add_message"));
+
+        let public_key = signer.get_public_key(ACCOUNT_ID, NETWORK_ID).await.unwrap();
+        let signature = signer
+            .sign_message(&message, ACCOUNT_ID, NETWORK_ID)
+            .await
+            .unwrap();
+
+        assert_eq!(public_key, key_pair.public_key());
+        assert!(signature.verify_prehashed(&CryptoHash::hash_bytes(&message).0, &public_key));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_auth_token() {
+        let key_pair = KeyPair::from_random(KeyType::ED25519);
+        let url = start_server(&key_pair);
+        let signer = RemoteSigner::new(&url, "wrong-token");
+        let message = transaction(&key_pair, "app.testnet", function_call("add_message"));
+
+        let result = signer.sign_message(&message, ACCOUNT_ID, NETWORK_ID).await;
+
+        assert!(result.unwrap_err().to_string().contains("401"));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_disallowed_transactions() {
+        let key_pair = KeyPair::from_random(KeyType::ED25519);
+        let url = start_server(&key_pair);
+        let signer = RemoteSigner::new(&url, AUTH_TOKEN);
+        let messages = vec![
+            (
+                transaction(&key_pair, "evil.testnet", function_call("add_message")),
+                "403",
+            ),
+            (
+                transaction(&key_pair, "app.testnet", function_call("withdraw")),
+                "403",
+            ),
+            (
+                transaction(
+                    &key_pair,
+                    "app.testnet",
+                    Action::Transfer(TransferAction { deposit: 1 }),
+                ),
+                "403",
+            ),
+            (b"not a transaction".to_vec(), "400"),
+        ];
+
+        for (message, status) in messages {
+            let result = signer.sign_message(&message, ACCOUNT_ID, NETWORK_ID).await;
+
+            assert!(result.unwrap_err().to_string().contains(status));
+        }
+    }
+
+    #[test]
+    pub fn test_rejects_invalid_secret_key() {
+        let result = router(config("ed25519:invalid".to_string()));
+
+        assert!(matches!(
+            result,
+            Err(ConfigError::InvalidSecretKey { account_id, .. }) if account_id == ACCOUNT_ID
+        ));
+    }
+}